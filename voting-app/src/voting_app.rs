@@ -17,13 +17,171 @@ pub struct ElectionInfo<M: ManagedTypeApi> {
     pub encryption_public_key: Option<ManagedBuffer<M>>, // For threshold-encrypted voting
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct ResultSnapshot<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub candidates: ManagedVec<M, ManagedBuffer<M>>, // Tallied candidates at snapshot time
+    pub counts: ManagedVec<M, u64>,                  // Aligned with `candidates`
+}
+
+/// Would-be results and unmet preconditions, as returned by `previewFinalize`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct FinalizationPreview<M: ManagedTypeApi> {
+    pub candidates: ManagedVec<M, ManagedBuffer<M>>,
+    pub counts: ManagedVec<M, u64>, // Aligned with candidates
+    pub blockers: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// One election's entry in `getResultsBatch`, holding the same pairs `getElectionResults` returns
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct ElectionResults<M: ManagedTypeApi> {
+    pub election_id: u64,
+    pub candidates: ManagedVec<M, ManagedBuffer<M>>,
+    pub counts: ManagedVec<M, u64>, // Aligned with candidates
+}
+
+/// Everything needed to verify a finalized election independently, as returned by `getAuditBundle`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct AuditBundle<M: ManagedTypeApi> {
+    pub info: ElectionInfo<M>,
+    pub candidates: ManagedVec<M, ManagedBuffer<M>>,
+    pub counts: ManagedVec<M, u64>, // Aligned with candidates
+    pub results_hash: ManagedBuffer<M>,
+    pub votes_root: Option<ManagedBuffer<M>>, // None when no vote receipt was recorded
+    pub ballots_cast: u64,                    // Address-bound and nullifier ballots
+    pub eligible_at_finalization: u64,
+    pub min_weight_reached: bool,
+    pub finalized_at: u64,
+}
+
+/// Undecoded view of an election's storage, for diagnosing layouts written before an upgrade
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ElectionRaw<M: ManagedTypeApi> {
+    pub info_bytes: ManagedBuffer<M>, // Empty when no ElectionInfo is stored
+    pub candidates_empty: bool,
+    pub eligible_voters_empty: bool,
+    pub has_voted_empty: bool,
+    pub encrypted_votes_empty: bool,
+    pub final_candidates_empty: bool,
+    pub final_counts_empty: bool,
+}
+
+/// Snapshot of the contract-wide operational parameters
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ContractConfig<M: ManagedTypeApi> {
+    pub organizer: ManagedAddress<M>,
+    pub election_count: u64,
+    pub max_migration_elections: u64,
+    pub paused: bool,
+    pub max_election_duration: u64,
+    pub min_candidates: u64,
+    pub max_candidates: u64,
+    pub organizer_renounced: bool,
+    pub pending_organizer: Option<ManagedAddress<M>>,
+    pub election_defaults: Option<ElectionDefaults>,
+}
+
+/// Election counts per voting method, as returned by `getElectionMethodStats`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ElectionMethodStats {
+    pub single_choice: u64,
+    pub multi_choice: u64,
+    pub ranked: u64,
+    pub commit_reveal: u64,
+    pub encrypted: u64,
+}
+
+/// Settings copied into every election created after `setElectionDefaults`
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ElectionDefaults {
+    pub min_total_weight: u64,
+    pub ballot_order_mode: BallotOrderMode,
+    pub write_ins_enabled: bool, // Only applied to open-tally elections
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum VotingMode {
     DirectVoting,
     MerkleProof,
+    CommitReveal, // `commitVote` then `revealVote`, stored by `setRevealWindow`
+    RankedChoice, // `voteRanked` preference lists, stored by `setRankedChoice`
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BallotOrderMode {
+    Insertion,
+    Alphabetical,
+    Randomized,
+}
+
+/// How open-tally results are computed at finalization
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TallyMethod {
+    Plurality,
+    Borda, // Ranked elections only
 }
 
+/// Upper bound for iterating every election in a single transaction
+pub const MAX_MIGRATION_ELECTIONS: u64 = 100;
+
+/// Most election ids `endElectionBatch` accepts in one call
+pub const MAX_END_BATCH: u64 = 100;
+
+/// Argument `renounceOrganizer` requires, so the call cannot be made by accident
+pub const RENOUNCE_CONFIRMATION: &[u8] = b"RENOUNCE_ORGANIZER";
+
+/// How long after finalization `reopenElection` may still undo it
+pub const REOPEN_WINDOW_SECONDS: u64 = 3_600;
+
+/// Per-voter views only scan this many of the most recent elections
+pub const MAX_ELECTION_SCAN: u64 = 100;
+
+/// Longest voting window, start to end, until the organizer sets `setMaxElectionDuration`
+pub const DEFAULT_MAX_ELECTION_DURATION: u64 = 90 * 24 * 3_600;
+
+/// A ballot needs a choice, so elections are created with at least this many candidates
+pub const MIN_CANDIDATES: usize = 2;
+
+/// Upper bound on an election's candidate list, keeping `endElection` within gas limits
+pub const MAX_CANDIDATES: usize = 50;
+
+/// Longest candidate name accepted, in bytes, bounding per-candidate storage
+pub const MAX_CANDIDATE_NAME_LEN: usize = 128;
+
+/// Ranked elections accept at most this many ballots, bounding every pairwise tally
+pub const MAX_RANKED_BALLOTS: usize = 500;
+
+/// Codes returned by `getVoterEligibilitySource`
+pub const ELIGIBILITY_NONE: u8 = 0;
+pub const ELIGIBILITY_LIST: u8 = 1;
+pub const ELIGIBILITY_MERKLE: u8 = 2;
+pub const ELIGIBILITY_TOKEN: u8 = 3;
+
+/// Codes returned by `getEndedUnfinalizedElections`
+pub const BLOCKED_READY: u8 = 0;
+pub const BLOCKED_BELOW_MIN_WEIGHT: u8 = 1;
+pub const BLOCKED_AWAITING_DECRYPTION: u8 = 2;
+pub const BLOCKED_AWAITING_REVEAL: u8 = 3;
+
+/// Ballot kinds for `setAllowedMethods`, combined as a bit mask
+pub const METHOD_SINGLE_CHOICE: u8 = 1;
+pub const METHOD_APPROVAL: u8 = 2;
+pub const METHOD_RANKED: u8 = 4;
+
+/// Depth of the per-election vote receipt tree, i.e. at most 2^20 receipts per election
+pub const VOTES_TREE_DEPTH: u32 = 20;
+
 #[multiversx_sc::contract]
 pub trait VotingApp {
     #[init]
@@ -45,40 +203,27 @@ pub trait VotingApp {
         encryption_public_key: OptionalValue<ManagedBuffer>,
         candidates: MultiValueEncoded<ManagedBuffer>,
     ) -> u64 {
-        self.require_organizer();
+        self.require_creator();
         require!(!name.is_empty(), "Election name cannot be empty");
         require!(start_time < end_time, "Start time must be before end time");
-        require!(candidates.len() > 0, "Election must have at least one candidate");
+        require!(end_time - start_time <= self.get_max_election_duration(), "Election too long");
+        require!(candidates.len() >= MIN_CANDIDATES, "Election must have at least two candidates");
+        require!(candidates.len() <= MAX_CANDIDATES, "Too many candidates");
+        let candidates = candidates.to_vec();
+        for candidate in candidates.iter() {
+            self.require_valid_candidate_name(&candidate);
+        }
         
-        let current_timestamp = self.blockchain().get_block_timestamp();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
         require!(start_time >= current_timestamp, "Election start time cannot be in the past");
 
-        let election_id = self.last_election_id().get() + 1;
-        self.last_election_id().set(election_id);
-
         let pub_key = match encryption_public_key {
             OptionalValue::Some(key) => Some(key),
             OptionalValue::None => None,
         };
 
-        let mut candidates_vec = ManagedVec::new();
-        for candidate in candidates {
-            self.candidates(election_id).insert(candidate.clone());
-            candidates_vec.push(candidate);
-        }
-
-        let election_info = ElectionInfo {
-            id: election_id,
-            name,
-            start_time,
-            end_time,
-            is_finalized: false,
-            candidates: candidates_vec,
-            merkle_root: None,
-            encryption_public_key: pub_key,
-        };
-        self.election_info(election_id).set(election_info);
-
+        let election_id = self.store_new_election(name, start_time, end_time, candidates, None, pub_key);
+        self.apply_election_defaults(election_id);
         election_id
     }
 
@@ -91,22 +236,185 @@ pub trait VotingApp {
         merkle_root: ManagedBuffer,
         candidates: MultiValueEncoded<ManagedBuffer>,
     ) -> u64 {
-        self.require_organizer();
+        self.require_creator();
         require!(!name.is_empty(), "Election name cannot be empty");
         require!(start_time < end_time, "Start time must be before end time");
+        require!(end_time - start_time <= self.get_max_election_duration(), "Election too long");
         require!(merkle_root.len() == 32, "Merkle root must be 32 bytes (keccak256)");
-        require!(candidates.len() > 0, "Election must have at least one candidate");
+        require!(candidates.len() >= MIN_CANDIDATES, "Election must have at least two candidates");
+        require!(candidates.len() <= MAX_CANDIDATES, "Too many candidates");
+        let candidates = candidates.to_vec();
+        for candidate in candidates.iter() {
+            self.require_valid_candidate_name(&candidate);
+        }
 
-        let current_timestamp = self.blockchain().get_block_timestamp();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
         require!(start_time >= current_timestamp, "Election start time cannot be in the past");
 
+        let election_id =
+            self.store_new_election(name, start_time, end_time, candidates, Some(merkle_root), None);
+        self.apply_election_defaults(election_id);
+        election_id
+    }
+
+    /// Creators may create elections but cannot administer them; that stays with the organizer
+    #[endpoint(addCreator)]
+    fn add_creator(&self, creator: ManagedAddress) {
+        self.require_organizer();
+        require!(self.creators().insert(creator), "Already a creator");
+    }
+
+    #[endpoint(removeCreator)]
+    fn remove_creator(&self, creator: ManagedAddress) {
+        self.require_organizer();
+        require!(self.creators().remove(&creator), "Not a creator");
+    }
+
+    /// Admins share every organizer permission except managing admins and handing over the organizer role
+    #[endpoint(addAdmin)]
+    fn add_admin(&self, admin: ManagedAddress) {
+        self.require_main_organizer();
+        require!(admin != self.organizer().get(), "Already the organizer");
+        require!(self.admins().insert(admin), "Already an admin");
+    }
+
+    #[endpoint(removeAdmin)]
+    fn remove_admin(&self, admin: ManagedAddress) {
+        self.require_main_organizer();
+        require!(self.admins().remove(&admin), "Not an admin");
+    }
+
+    #[view(isAdmin)]
+    fn is_admin(&self, address: ManagedAddress) -> bool {
+        self.admins().contains(&address)
+    }
+
+    /// Re-runs an election from scratch: ballot, settings and eligibility list are copied, votes are not.
+    /// Registered candidates only; proven candidates and Merkle registrations start over.
+    /// Cancelled elections are cloned from the snapshot taken at cancellation, without their cleared voter list.
+    #[endpoint(cloneElection)]
+    fn clone_election(&self, election_id: u64, new_start: u64, new_end: u64) -> u64 {
+        self.require_organizer();
+        let info = if self.cancelled_info(election_id).is_empty() {
+            require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+            self.election_info(election_id).get()
+        } else {
+            self.cancelled_info(election_id).get()
+        };
+        require!(new_start < new_end, "Start time must be before end time");
+        require!(new_end - new_start <= self.get_max_election_duration(), "Election too long");
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(new_start >= current_timestamp, "Election start time cannot be in the past");
+
+        let clone_id = self.store_new_election(
+            info.name.clone(),
+            new_start,
+            new_end,
+            info.candidates.clone(),
+            info.merkle_root.clone(),
+            info.encryption_public_key.clone(),
+        );
+
+        for voter in self.eligible_voters(election_id).iter() {
+            self.eligible_voters(clone_id).insert(voter);
+        }
+        for candidate in info.candidates.iter() {
+            let cap_mapper = self.candidate_cap(election_id, &candidate);
+            if !cap_mapper.is_empty() {
+                self.candidate_cap(clone_id, &candidate).set(cap_mapper.get());
+            }
+        }
+        if !self.candidate_root(election_id).is_empty() {
+            self.candidate_root(clone_id).set(self.candidate_root(election_id).get());
+        }
+        if !self.anonymous_root(election_id).is_empty() {
+            self.anonymous_root(clone_id).set(self.anonymous_root(election_id).get());
+        }
+
+        self.open_tally(clone_id).set(self.open_tally(election_id).get());
+        self.ballot_order_mode(clone_id).set(self.ballot_order_mode(election_id).get());
+        self.min_total_weight(clone_id).set(self.min_total_weight(election_id).get());
+        self.write_ins_enabled(clone_id).set(self.write_ins_enabled(election_id).get());
+        self.auto_revote_on_tie(clone_id).set(self.auto_revote_on_tie(election_id).get());
+        self.revote_duration(clone_id).set(self.revote_duration(election_id).get());
+        self.max_choices(clone_id).set(self.max_choices(election_id).get());
+        self.last_vote_wins(clone_id).set(self.last_vote_wins(election_id).get());
+        self.open_registration(clone_id).set(self.open_registration(election_id).get());
+        self.restrict_organizer(clone_id).set(self.restrict_organizer(election_id).get());
+        self.reveal_duration(clone_id).set(self.reveal_duration(election_id).get());
+        self.max_delegated_weight(clone_id).set(self.max_delegated_weight(election_id).get());
+        self.reveal_deposit(clone_id).set(self.reveal_deposit(election_id).get());
+        self.deposit_beneficiary(clone_id).set(self.organizer().get());
+        self.display_threshold(clone_id).set(self.display_threshold(election_id).get());
+        self.ranked_choice(clone_id).set(self.ranked_choice(election_id).get());
+        self.voting_mode(clone_id).set(self.stored_voting_mode(election_id, &info));
+        self.tally_method(clone_id).set(self.tally_method(election_id).get());
+        if !self.stake_contract(election_id).is_empty() {
+            self.stake_contract(clone_id).set(self.stake_contract(election_id).get());
+        }
+        if !self.required_token(election_id).is_empty() {
+            self.required_token(clone_id).set(self.required_token(election_id).get());
+        }
+
+        clone_id
+    }
+
+    /// Bounds how long new, cloned and extended elections can run, so eligibility cannot be held open indefinitely
+    #[endpoint(setMaxElectionDuration)]
+    fn set_max_election_duration(&self, max_duration: u64) {
+        self.require_organizer();
+        require!(max_duration > 0, "Duration must be positive");
+        self.max_election_duration().set(max_duration);
+    }
+
+    #[view(maxElectionDuration)]
+    fn get_max_election_duration(&self) -> u64 {
+        if self.max_election_duration().is_empty() {
+            return DEFAULT_MAX_ELECTION_DURATION;
+        }
+        self.max_election_duration().get()
+    }
+
+    /// Defaults are copied at creation time; changing them later leaves existing elections untouched,
+    /// and the per-election setters still override them before the election starts
+    #[endpoint(setElectionDefaults)]
+    fn set_election_defaults(&self, min_total_weight: u64, ballot_order_mode: BallotOrderMode, write_ins_enabled: bool) {
+        self.require_organizer();
+        self.election_defaults().set(ElectionDefaults {
+            min_total_weight,
+            ballot_order_mode,
+            write_ins_enabled,
+        });
+    }
+
+    fn apply_election_defaults(&self, election_id: u64) {
+        if self.election_defaults().is_empty() {
+            return;
+        }
+
+        let defaults = self.election_defaults().get();
+        self.min_total_weight(election_id).set(defaults.min_total_weight);
+        self.ballot_order_mode(election_id).set(defaults.ballot_order_mode);
+        if self.election_info(election_id).get().encryption_public_key.is_none() {
+            self.write_ins_enabled(election_id).set(defaults.write_ins_enabled);
+        }
+    }
+
+    fn store_new_election(
+        &self,
+        name: ManagedBuffer,
+        start_time: u64,
+        end_time: u64,
+        candidates: ManagedVec<ManagedBuffer>,
+        merkle_root: Option<ManagedBuffer>,
+        encryption_public_key: Option<ManagedBuffer>,
+    ) -> u64 {
         let election_id = self.last_election_id().get() + 1;
         self.last_election_id().set(election_id);
 
-        let mut candidates_vec = ManagedVec::new();
-        for candidate in candidates {
-            self.candidates(election_id).insert(candidate.clone());
-            candidates_vec.push(candidate);
+        for candidate in candidates.iter() {
+            self.candidates(election_id).insert(candidate.clone_value());
         }
 
         let election_info = ElectionInfo {
@@ -115,12 +423,20 @@ pub trait VotingApp {
             start_time,
             end_time,
             is_finalized: false,
-            candidates: candidates_vec,
-            merkle_root: Some(merkle_root),
-            encryption_public_key: None,
+            candidates,
+            merkle_root,
+            encryption_public_key,
         };
-        self.election_info(election_id).set(election_info);
+        self.election_info(election_id).set(&election_info);
+        let caller = self.blockchain().get_caller();
+        self.elections_created(&caller).update(|count| *count += 1);
+        self.election_creator(election_id).set(&caller);
+        self.election_organizer(election_id).set(caller);
+        let mode = if election_info.merkle_root.is_some() { VotingMode::MerkleProof } else { VotingMode::DirectVoting };
+        self.voting_mode(election_id).set(mode);
+        self.ballot_seed(election_id).set(self.blockchain().get_block_random_seed().as_managed_buffer());
 
+        self.election_created_event(election_id, &election_info.name);
         election_id
     }
 
@@ -131,199 +447,3233 @@ pub trait VotingApp {
 
         let mut info = self.election_info(election_id).get();
         require!(!info.is_finalized, "Election already finalized");
+        // Ballots tallied in the clear cannot be mixed with encrypted ones
+        require!(!self.open_tally(election_id).get(), "Election uses an open tally");
         
         // Store the encryption public key (binary encoded: p || g || h)
         info.encryption_public_key = Some(public_key);
         self.election_info(election_id).set(info);
     }
 
+    /// Opts an election without an encryption key into an open tally: ballots are candidate names,
+    /// counted as they are cast and visible through `getElectionResults`, and `endElection`
+    /// finalizes from those counts. Without it, keyless elections wait for `setEncryptionPublicKey`.
+    #[endpoint(setOpenTally)]
+    fn set_open_tally(&self, election_id: u64, enabled: bool) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(info.encryption_public_key.is_none(), "Election has an encryption key");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.open_tally(election_id).set(enabled);
+    }
+
+    /// Returns how many of the addresses were new; duplicates are skipped
     #[endpoint(addVoters)]
-    fn add_voters(&self, election_id: u64, voters: MultiValueEncoded<ManagedAddress>) {
+    fn add_voters(&self, election_id: u64, voters: MultiValueEncoded<ManagedAddress>) -> u64 {
         self.require_organizer();
         require!(!self.election_info(election_id).is_empty(), "Election does not exist");
 
         let info = self.election_info(election_id).get();
         require!(!info.is_finalized, "Election ended");
 
+        let mut added = 0u64;
         for voter in voters {
-            self.eligible_voters(election_id).insert(voter);
+            if self.eligible_voters(election_id).insert(voter) {
+                added += 1;
+            }
         }
+        added
     }
 
-    #[endpoint(endElection)]
-    fn end_election(&self, election_id: u64) {
+    /// Adds voters whose `vote` counts `weight` instead of 1; re-adding a voter updates their weight.
+    /// Returns how many of the addresses were new.
+    #[endpoint(addWeightedVoters)]
+    fn add_weighted_voters(&self, election_id: u64, voters: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>) -> u64 {
         self.require_organizer();
         require!(!self.election_info(election_id).is_empty(), "Election does not exist");
-        
+
         let info = self.election_info(election_id).get();
-        require!(!info.is_finalized, "Election already finalized");
-        
-        let current_timestamp = self.blockchain().get_block_timestamp();
-        require!(current_timestamp > info.end_time, "Election not yet ended");
+        require!(!info.is_finalized, "Election ended");
+        require!(self.open_tally(election_id).get(), "Voter weights require an open-tally election");
 
-        // Just end voting - do NOT finalize yet
-        // Finalization happens only when results are published (after threshold decryption)
-        // No state change needed - election naturally ends at end_time
+        let mut added = 0u64;
+        for entry in voters {
+            let (voter, weight) = entry.into_tuple();
+            require!(weight > 0, "Weight must be positive");
+            require!(!self.has_voted(election_id).contains(&voter), "Voter already voted");
+
+            self.voter_weight(election_id, &voter).set(weight);
+            if self.eligible_voters(election_id).insert(voter) {
+                added += 1;
+            }
+        }
+        added
     }
 
-    #[endpoint(forceEndElection)]
-    fn force_end_election(&self, election_id: u64) {
+    /// Keeps the organizer from voting in the election, even when listed (off by default)
+    #[endpoint(setRestrictOrganizer)]
+    fn set_restrict_organizer(&self, election_id: u64, restricted: bool) {
         self.require_organizer();
         require!(!self.election_info(election_id).is_empty(), "Election does not exist");
-        let mut info = self.election_info(election_id).get();
-        require!(!info.is_finalized, "Election already finalized");
 
-        let current_timestamp = self.blockchain().get_block_timestamp();
-        // Prevent redundant force end calls
-        require!(current_timestamp <= info.end_time, "Election already ended");
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
 
-        // Force end voting immediately by updating end_time to slightly in the past
-        // This ensures publish_results (which checks > end_time) works immediately
-        info.end_time = current_timestamp.saturating_sub(1);
-        self.election_info(election_id).set(&info);
+        self.restrict_organizer(election_id).set(restricted);
     }
 
-    #[endpoint(vote)]
-    fn vote(&self, election_id: u64, encrypted_ballot: ManagedBuffer) {
-        let caller = self.blockchain().get_caller();
-        
+    /// Lets anyone add themselves to the eligibility list through `registerSelf` (closed by default)
+    #[endpoint(setOpenRegistration)]
+    fn set_open_registration(&self, election_id: u64, enabled: bool) {
+        self.require_organizer();
         require!(!self.election_info(election_id).is_empty(), "Election does not exist");
-        
+
         let info = self.election_info(election_id).get();
-        let current_timestamp = self.blockchain().get_block_timestamp();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
 
-        require!(current_timestamp >= info.start_time, "Election not started");
-        require!(current_timestamp <= info.end_time, "Election ended");
-        require!(!info.is_finalized, "Election finalized");
-        require!(info.encryption_public_key.is_some(), "Election encryption keys not set");
+        self.open_registration(election_id).set(enabled);
+    }
 
-        require!(self.eligible_voters(election_id).contains(&caller), "Not eligible to vote");
-        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+    /// Adds the caller to the eligibility list of an open-registration election until it ends
+    #[endpoint(registerSelf)]
+    fn register_self(&self, election_id: u64) {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.open_registration(election_id).get(), "Registration closed");
 
-        // Store the encrypted ballot (ElGamal ciphertext from client)
-        self.encrypted_votes(election_id).insert(encrypted_ballot);
-        
-        // Record that this voter has voted
-        self.has_voted(election_id).insert(caller);
-    }
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(!info.is_finalized, "Election finalized");
+        require!(current_timestamp <= info.end_time, "Election ended");
 
-    #[endpoint(voteWithMerkle)]
-    fn vote_with_merkle(
-        &self,
-        election_id: u64,
-        nullifier: ManagedBuffer,
-        encrypted_ballot: ManagedBuffer,
-        merkle_proof: MultiValueEncoded<ManagedBuffer>,
-    ) {
         let caller = self.blockchain().get_caller();
+        require!(self.eligible_voters(election_id).insert(caller), "Already registered");
+    }
+
+    /// Declares the size of a Merkle election's eligible set, reported by `getEligibilityCount`
+    #[endpoint(setEligibleCountOverride)]
+    fn set_eligible_count_override(&self, election_id: u64, count: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(
+            self.election_info(election_id).get().merkle_root.is_some(),
+            "Election not configured for Merkle voting",
+        );
 
+        self.eligible_count_override(election_id).set(count);
+    }
+
+    /// Corrects an eligibility mistake; a voter whose vote is already counted cannot be removed
+    #[endpoint(removeVoter)]
+    fn remove_voter(&self, election_id: u64, voter: ManagedAddress) {
+        self.require_organizer();
         require!(!self.election_info(election_id).is_empty(), "Election does not exist");
 
         let info = self.election_info(election_id).get();
-        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(!info.is_finalized, "Election ended");
+        require!(self.eligible_voters(election_id).contains(&voter), "Voter not in eligible list");
+        require!(!self.has_voted(election_id).contains(&voter), "Voter already voted");
 
-        require!(current_timestamp >= info.start_time, "Election not started");
+        self.eligible_voters(election_id).remove(&voter);
+    }
+
+    /// Hybrid eligibility: a voter in the election's Merkle tree proves membership once and then votes
+    /// through `vote` and the other plain ballot endpoints, alongside the organizer-managed list.
+    #[endpoint(registerWithMerkleProof)]
+    fn register_with_merkle_proof(&self, election_id: u64, merkle_proof: MultiValueEncoded<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
         require!(current_timestamp <= info.end_time, "Election ended");
-        require!(!info.is_finalized, "Election finalized");
-        require!(info.encryption_public_key.is_some(), "Election encryption keys not set");
         require!(info.merkle_root.is_some(), "Election not configured for Merkle voting");
+        require!(!self.merkle_registered(election_id).contains(&caller), "Already registered");
 
-        // Nullifier prevents double voting without storing voter address
-        require!(
-            !self.used_nullifiers(election_id).contains(&nullifier),
-            "Already voted",
-        );
-
-        // Verify Merkle membership of caller
         let leaf = self.hash_address(&caller);
+        let is_valid = self.verify_merkle_proof_leaf(&leaf, &info.merkle_root.unwrap(), &merkle_proof);
+        require!(is_valid, "Invalid Merkle proof - not eligible");
+
+        self.merkle_registered(election_id).insert(caller);
+    }
+
+    #[endpoint(setCandidateRoot)]
+    fn set_candidate_root(&self, election_id: u64, candidate_root: ManagedBuffer) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(candidate_root.len() == 32, "Candidate root must be 32 bytes (keccak256)");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.candidate_root(election_id).set(candidate_root);
+    }
+
+    /// Opts the election into `voteAnonymous`, against a root of keccak256(nullifier) leaves.
+    /// Kept apart from the voter root, whose address leaves are public and would double as nullifiers.
+    #[endpoint(setAnonymousRoot)]
+    fn set_anonymous_root(&self, election_id: u64, anonymous_root: ManagedBuffer) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(anonymous_root.len() == 32, "Anonymous root must be 32 bytes (keccak256)");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.anonymous_root(election_id).set(anonymous_root);
+    }
+
+    /// Open candidacy: anyone can register a name proven to be in the allowed candidate set
+    #[endpoint(registerCandidateWithProof)]
+    fn register_candidate_with_proof(
+        &self,
+        election_id: u64,
+        name: ManagedBuffer,
+        proof: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(!self.candidate_root(election_id).is_empty(), "Election has no candidate allowlist");
+        self.require_valid_candidate_name(&name);
+
+        let mut info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+        require!(!self.candidates(election_id).contains(&name), "Candidate already registered");
+
+        let leaf = self.hash_leaf(&name);
         let is_valid = self.verify_merkle_proof_leaf(
             &leaf,
-            &info.merkle_root.unwrap(),
-            &merkle_proof,
+            &self.candidate_root(election_id).get(),
+            &proof,
         );
-        require!(is_valid, "Invalid Merkle proof - not eligible");
+        require!(is_valid, "Invalid Merkle proof - candidate not allowed");
+        require!(info.candidates.len() < MAX_CANDIDATES, "Too many candidates");
 
-        self.used_nullifiers(election_id).insert(nullifier);
-        self.encrypted_votes(election_id).insert(encrypted_ballot);
+        self.candidates(election_id).insert(name.clone());
+        info.candidates.push(name);
+        self.election_info(election_id).set(info);
+    }
+
+    /// Completes the candidate list before voting starts; names already registered are skipped
+    #[endpoint(addCandidates)]
+    fn add_candidates(&self, election_id: u64, candidates: MultiValueEncoded<ManagedBuffer>) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        for candidate in candidates {
+            self.require_valid_candidate_name(&candidate);
+            if self.candidates(election_id).insert(candidate.clone()) {
+                require!(info.candidates.len() < MAX_CANDIDATES, "Too many candidates");
+                info.candidates.push(candidate);
+            }
+        }
+        self.election_info(election_id).set(info);
+    }
+
+    /// Attaches an opaque blob (e.g. JSON or a URL) to a candidate; the candidate set itself is unchanged
+    #[endpoint(setCandidateMetadata)]
+    fn set_candidate_metadata(&self, election_id: u64, candidate: ManagedBuffer, metadata: ManagedBuffer) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.candidates(election_id).contains(&candidate), "Invalid candidate");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.candidate_metadata(election_id, &candidate).set(metadata);
+    }
+
+    /// Empty when no metadata was set
+    #[view(getCandidateMetadata)]
+    fn get_candidate_metadata(&self, election_id: u64, candidate: ManagedBuffer) -> ManagedBuffer {
+        self.candidate_metadata(election_id, &candidate).get()
+    }
+
+    /// Mitigates ballot-order bias; randomized order is stable, derived from the creation block seed
+    #[endpoint(setBallotOrderMode)]
+    fn set_ballot_order_mode(&self, election_id: u64, mode: BallotOrderMode) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.ballot_order_mode(election_id).set(mode);
+    }
+
+    /// Lets voters pick up to `max_choices` candidates through `voteMulti`, each receiving one vote
+    #[endpoint(setMaxChoices)]
+    fn set_max_choices(&self, election_id: u64, max_choices: u32) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(max_choices > 0, "Max choices must be positive");
+
+        let info = self.election_info(election_id).get();
+        require!(self.open_tally(election_id).get(), "Multi-choice voting requires an open-tally election");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.max_choices(election_id).set(max_choices);
+    }
+
+    /// Repeated `vote` calls then move the voter's choice instead of reverting (sentiment polls)
+    #[endpoint(setLastVoteWins)]
+    fn set_last_vote_wins(&self, election_id: u64, enabled: bool) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(self.open_tally(election_id).get(), "Last-vote-wins requires an open-tally election");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.last_vote_wins(election_id).set(enabled);
+    }
+
+    /// Bounds how much voting power a single delegate can accumulate (0 = unlimited)
+    #[endpoint(setMaxDelegatedWeight)]
+    fn set_max_delegated_weight(&self, election_id: u64, max_weight: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.max_delegated_weight(election_id).set(max_weight);
+    }
+
+    /// Switches the election to commit-reveal: ballots are committed during the voting window and
+    /// revealed within `reveal_duration` seconds after it, so no running tally exists while voting
+    #[endpoint(setRevealWindow)]
+    fn set_reveal_window(&self, election_id: u64, reveal_duration: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(self.open_tally(election_id).get(), "Commit-reveal requires an open-tally election");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.switch_voting_mode(election_id, VotingMode::CommitReveal, reveal_duration > 0);
+        self.reveal_duration(election_id).set(reveal_duration);
+    }
+
+    #[endpoint(setDisplayThreshold)]
+    fn set_display_threshold(&self, election_id: u64, threshold: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        self.display_threshold(election_id).set(threshold);
+    }
+
+    #[endpoint(setRevealDeposit)]
+    fn set_reveal_deposit(&self, election_id: u64, deposit: BigUint) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.reveal_duration(election_id).get() > 0, "Election does not use commit-reveal");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.reveal_deposit(election_id).set(deposit);
+        self.deposit_beneficiary(election_id).set(self.organizer().get());
+    }
+
+    /// Weighs each `vote` by the caller's stake, as returned by `getStake(address)` on `stake_contract`
+    #[endpoint(setStakeContract)]
+    fn set_stake_contract(&self, election_id: u64, stake_contract: ManagedAddress) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.blockchain().is_smart_contract(&stake_contract), "Stake contract must be a smart contract");
+
+        let info = self.election_info(election_id).get();
+        require!(self.open_tally(election_id).get(), "Stake weights require an open-tally election");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+        require!(self.nft_gate(election_id).is_empty(), "Election weighs by NFT tier");
+
+        self.stake_contract(election_id).set(stake_contract);
+    }
+
+    /// Gates `vote` on an NFT of `collection`, weighing each vote by the tier in the NFT's first attribute byte
+    #[endpoint(setNftGate)]
+    fn set_nft_gate(&self, election_id: u64, collection: EsdtTokenIdentifier) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(collection.is_valid_esdt_identifier(), "Invalid collection");
+
+        let info = self.election_info(election_id).get();
+        require!(self.open_tally(election_id).get(), "NFT weights require an open-tally election");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+        require!(self.stake_contract(election_id).is_empty(), "Election weighs by stake");
+
+        self.nft_gate(election_id).set(collection);
+    }
+
+    /// Lets holders of any positive `token` balance vote through `voteWithToken`, listed or not
+    #[endpoint(setRequiredToken)]
+    fn set_required_token(&self, election_id: u64, token: EsdtTokenIdentifier) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(token.is_valid_esdt_identifier(), "Invalid token");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.required_token(election_id).set(token);
+    }
+
+    /// Tiers without a configured weight do not qualify to vote
+    #[endpoint(setTierWeight)]
+    fn set_tier_weight(&self, election_id: u64, tier: u8, weight: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.tier_weight(election_id, tier).set(weight);
+    }
+
+    /// Picks which NFT of the gate collection the caller votes with; it must still be held when voting
+    #[endpoint(registerVotingNft)]
+    fn register_voting_nft(&self, election_id: u64, nonce: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.nft_gate(election_id).is_empty(), "Election is not NFT-gated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        if let Err(reason) = self.nft_tier_weight(election_id, &caller, nonce) {
+            sc_panic!(reason);
+        }
+        self.voting_nft(election_id, &caller).set(nonce);
+    }
+
+    /// Restricts which ballot kinds the election accepts, as a mask of METHOD_* flags; 0 lifts the restriction
+    #[endpoint(setAllowedMethods)]
+    fn set_allowed_methods(&self, election_id: u64, methods: u8) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(
+            methods & !(METHOD_SINGLE_CHOICE | METHOD_APPROVAL | METHOD_RANKED) == 0,
+            "Unknown voting method",
+        );
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.allowed_methods(election_id).set(methods);
+    }
+
+    /// Ranked elections take their ballots through `voteRanked` instead of `vote`, and report `VotingMode::RankedChoice`
+    #[endpoint(setRankedChoice)]
+    fn set_ranked_choice(&self, election_id: u64, enabled: bool) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(self.open_tally(election_id).get(), "Ranked ballots require an open-tally election");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.switch_voting_mode(election_id, VotingMode::RankedChoice, enabled);
+        self.ranked_choice(election_id).set(enabled);
+    }
+
+    /// Borda finalizes ranked elections by rank points instead of first preferences
+    #[endpoint(setTallyMethod)]
+    fn set_tally_method(&self, election_id: u64, method: TallyMethod) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(!self.election_info(election_id).get().is_finalized, "Election already finalized");
+        require!(
+            method == TallyMethod::Plurality || self.ranked_choice(election_id).get(),
+            "Borda requires ranked ballots",
+        );
+
+        self.tally_method(election_id).set(method);
+    }
+
+    /// Caps how many votes a candidate can receive (e.g. limited seats per option)
+    #[endpoint(setCandidateCap)]
+    fn set_candidate_cap(&self, election_id: u64, candidate: ManagedBuffer, cap: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.candidates(election_id).contains(&candidate), "Invalid candidate");
+        require!(cap > 0, "Cap must be positive");
+
+        let info = self.election_info(election_id).get();
+        require!(self.open_tally(election_id).get(), "Caps require an open-tally election");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.candidate_cap(election_id, &candidate).set(cap);
+    }
+
+    /// Stops a candidate from receiving new votes mid-election, optionally voiding the votes already cast
+    #[endpoint(disqualifyCandidate)]
+    fn disqualify_candidate(&self, election_id: u64, candidate: ManagedBuffer, zero_existing: bool) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        require!(self.open_tally(election_id).get(), "Disqualification requires an open-tally election");
+        require!(
+            self.candidates(election_id).contains(&candidate) || self.proven_candidates(election_id).contains(&candidate),
+            "Invalid candidate",
+        );
+        require!(self.disqualified(election_id).insert(candidate.clone()), "Candidate already disqualified");
+
+        if zero_existing {
+            let voided = self.vote_counts(election_id, &candidate).take();
+            let total = self.total_weighted_votes(election_id).get().checked_sub(voided);
+            require!(total.is_some(), "Vote count underflow");
+            self.total_weighted_votes(election_id).set(total.unwrap_or_default());
+        }
+    }
+
+    /// Results of this election stay provisional until co-signed by the notary
+    #[endpoint(setNotary)]
+    fn set_notary(&self, election_id: u64, notary: ManagedAddress) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+
+        self.notary(election_id).set(notary);
+    }
+
+    /// The notary signs `getResultsHash` with its account key; anyone may submit the signature
+    #[endpoint(notarizeResults)]
+    fn notarize_results(&self, election_id: u64, signature: ManagedBuffer) {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(!self.notary(election_id).is_empty(), "Election has no notary");
+        require!(!self.results_notarized(election_id).get(), "Results already notarized");
+
+        let results_hash = self.get_results_hash(election_id);
+        let notary = self.notary(election_id).get();
+        self.crypto()
+            .verify_ed25519(notary.as_managed_buffer(), &results_hash, &signature);
+
+        self.results_notarized(election_id).set(true);
+    }
+
+    /// Write-ins are moderated: they collect votes in a shadow tally until approved or rejected
+    #[endpoint(setWriteInsEnabled)]
+    fn set_write_ins_enabled(&self, election_id: u64, enabled: bool) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        require!(self.open_tally(election_id).get(), "Write-ins require an open-tally election");
+
+        self.write_ins_enabled(election_id).set(enabled);
+    }
+
+    /// Promotes a pending write-in to a regular candidate, carrying over its shadow votes
+    #[endpoint(approveWriteIn)]
+    fn approve_write_in(&self, election_id: u64, name: ManagedBuffer) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        require!(self.pending_write_ins(election_id).swap_remove(&name), "Unknown write-in");
+        require!(info.candidates.len() < MAX_CANDIDATES, "Too many candidates");
+
+        let votes = self.write_in_votes(election_id, &name).take();
+        self.candidates(election_id).insert(name.clone());
+        info.candidates.push(name.clone());
+        self.election_info(election_id).set(info);
+        self.add_vote(election_id, &name, votes);
+    }
+
+    /// Discards a pending write-in; its votes are voided and those voters cannot vote again
+    #[endpoint(rejectWriteIn)]
+    fn reject_write_in(&self, election_id: u64, name: ManagedBuffer) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        require!(self.pending_write_ins(election_id).swap_remove(&name), "Unknown write-in");
+
+        self.write_in_votes(election_id, &name).clear();
+    }
+
+    /// Finalization reverts until the cast ballots reach this total weight
+    #[endpoint(setMinTotalWeight)]
+    fn set_min_total_weight(&self, election_id: u64, min_total_weight: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.min_total_weight(election_id).set(min_total_weight);
+    }
+
+    /// Results only count as binding when at least `quorum` voters took part (see `isQuorumMet`)
+    #[endpoint(setQuorum)]
+    fn set_quorum(&self, election_id: u64, quorum: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        self.quorum(election_id).set(quorum);
+    }
+
+    /// Records the live open-tally counts so momentum can be charted later
+    #[endpoint(takeResultsSnapshot)]
+    fn take_results_snapshot(&self, election_id: u64) -> usize {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        require!(self.open_tally(election_id).get(), "Snapshots require an open-tally election");
+
+        let candidates = self.tally_candidates(election_id, &info);
+        let mut counts = ManagedVec::new();
+        for candidate in candidates.iter() {
+            counts.push(self.vote_counts(election_id, &candidate).get());
+        }
+
+        let snapshot = ResultSnapshot {
+            timestamp: self.blockchain().get_block_timestamp_seconds().as_u64_seconds(),
+            candidates,
+            counts,
+        };
+        self.result_snapshots(election_id).push(&snapshot)
+    }
+
+    /// Hands every election and the global organizer role to `new_owner` in one transaction, cancelling
+    /// any pending `proposeOrganizer` handoff. Capped at MAX_MIGRATION_ELECTIONS; larger deployments
+    /// migrate with `migrateElectionsChunk` first.
+    #[endpoint(migrateAllElectionsTo)]
+    fn migrate_all_elections_to(&self, new_owner: ManagedAddress) {
+        self.require_main_organizer();
+        require!(!new_owner.is_zero(), "Invalid organizer");
+        let last_id = self.last_election_id().get();
+        require!(
+            last_id <= MAX_MIGRATION_ELECTIONS,
+            "Too many elections, migrate in chunks",
+        );
+
+        self.migrate_elections(&new_owner, 1, last_id);
+        self.pending_organizer().clear();
+        self.organizer().set(new_owner);
+    }
+
+    /// First step of an organizer handoff; the role only moves once `new_organizer` calls `acceptOrganizer`
+    #[endpoint(proposeOrganizer)]
+    fn propose_organizer(&self, new_organizer: ManagedAddress) {
+        self.require_main_organizer();
+        require!(!new_organizer.is_zero(), "Invalid organizer");
+        self.pending_organizer().set(new_organizer);
+    }
+
+    #[endpoint(acceptOrganizer)]
+    fn accept_organizer(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.organizer_renounced().get(), "Organizer renounced");
+        require!(
+            !self.pending_organizer().is_empty() && self.pending_organizer().get() == caller,
+            "Not the pending organizer",
+        );
+
+        self.pending_organizer().clear();
+        self.organizer().set(caller);
+    }
+
+    /// Irreversibly gives up administration: every organizer- and creator-gated endpoint reverts afterwards,
+    /// while voting and views keep working. `confirmation` must equal RENOUNCE_CONFIRMATION.
+    #[endpoint(renounceOrganizer)]
+    fn renounce_organizer(&self, confirmation: ManagedBuffer) {
+        self.require_main_organizer();
+        require!(confirmation == *RENOUNCE_CONFIRMATION, "Invalid confirmation");
+
+        self.organizer().set(ManagedAddress::zero());
+        self.organizer_renounced().set(true);
+    }
+
+    /// Migrates elections `start_id..=end_id` without touching the global organizer
+    #[endpoint(migrateElectionsChunk)]
+    fn migrate_elections_chunk(&self, new_owner: ManagedAddress, start_id: u64, end_id: u64) {
+        self.require_main_organizer();
+        require!(!new_owner.is_zero(), "Invalid organizer");
+        require!(start_id > 0 && start_id <= end_id, "Invalid election range");
+        require!(
+            end_id - start_id < MAX_MIGRATION_ELECTIONS,
+            "Too many elections, migrate in chunks",
+        );
+
+        let end_id = end_id.min(self.last_election_id().get());
+        self.migrate_elections(&new_owner, start_id, end_id);
+    }
+
+    fn migrate_elections(&self, new_owner: &ManagedAddress, start_id: u64, end_id: u64) {
+        for id in start_id..=end_id {
+            if !self.election_info(id).is_empty() {
+                self.election_organizer(id).set(new_owner);
+            }
+        }
+    }
+
+    /// On a tie at finalization, automatically opens a revote among the tied candidates
+    #[endpoint(setAutoRevoteOnTie)]
+    fn set_auto_revote_on_tie(&self, election_id: u64, enabled: bool, revote_duration: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(!enabled || revote_duration > 0, "Revote duration must be positive");
+        require!(revote_duration <= self.get_max_election_duration(), "Election too long");
+
+        let info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        require!(self.open_tally(election_id).get(), "Auto revote requires an open-tally election");
+
+        self.auto_revote_on_tie(election_id).set(enabled);
+        self.revote_duration(election_id).set(revote_duration);
+    }
+
+    /// Groups related elections (e.g. the seats of one governance cycle) for summary views
+    #[endpoint(setElectionGroup)]
+    fn set_election_group(&self, election_id: u64, group_id: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(group_id > 0, "Group id must be positive");
+        require!(self.election_group(election_id).is_empty(), "Election already in a group");
+
+        self.election_group(election_id).set(group_id);
+        self.group_elections(group_id).insert(election_id);
+    }
+
+    /// Returns the id of the automatic revote election, if a tie triggered one
+    #[endpoint(endElection)]
+    fn end_election(&self, election_id: u64) -> OptionalValue<u64> {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        
+        let mut info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp > info.end_time, "Election not yet ended");
+        require!(
+            current_timestamp > info.end_time + self.reveal_duration(election_id).get(),
+            "Reveal window still open",
+        );
+        self.require_min_total_weight(election_id);
+
+        // Encrypted elections: just end voting - do NOT finalize yet
+        // Finalization happens only when results are published (after threshold decryption)
+        // No state change needed - election naturally ends at end_time
+        if !self.open_tally(election_id).get() {
+            return OptionalValue::None;
+        }
+
+        self.finalize_open_tally(election_id, &mut info)
+    }
+
+    /// `endElection` for several elections; ids that are unknown, already finalized or not ready yet
+    /// (still running, reveal window open, below the minimum weight, awaiting decryption) are skipped
+    #[endpoint(endElectionBatch)]
+    fn end_election_batch(&self, election_ids: MultiValueEncoded<u64>) {
+        self.require_organizer();
+        require!(election_ids.len() as u64 <= MAX_END_BATCH, "Too many elections");
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        for election_id in election_ids {
+            if self.election_info(election_id).is_empty() {
+                continue;
+            }
+            let mut info = self.election_info(election_id).get();
+            if info.is_finalized || current_timestamp <= info.end_time {
+                continue;
+            }
+            if self.finalization_block_reason(election_id, &info, current_timestamp) != BLOCKED_READY {
+                continue;
+            }
+
+            let _ = self.finalize_open_tally(election_id, &mut info);
+        }
+    }
+
+    /// Open-tally elections are finalized straight from the on-chain counts, opening a revote on a tie if configured
+    fn finalize_open_tally(&self, election_id: u64, info: &mut ElectionInfo<Self::Api>) -> OptionalValue<u64> {
+        let (candidates_vec, counts_vec) = self.tabulate_results(election_id);
+        self.do_finalize(election_id, info, &candidates_vec, &counts_vec);
+
+        if !self.auto_revote_on_tie(election_id).get() {
+            return OptionalValue::None;
+        }
+        self.open_revote_on_tie(info, &candidates_vec, &counts_vec)
+    }
+
+    /// Creates a follow-up election among the candidates tied at the (non-zero) top count. It inherits
+    /// the list and Merkle eligibility, listed weights, stake contract and candidate caps of the original;
+    /// NFT and token gates are not carried over. The window is clamped to the current max election duration.
+    fn open_revote_on_tie(
+        &self,
+        info: &ElectionInfo<Self::Api>,
+        candidates: &ManagedVec<ManagedBuffer>,
+        counts: &ManagedVec<u64>,
+    ) -> OptionalValue<u64> {
+        let top_count = counts.iter().max().unwrap_or_default();
+        let mut tied = ManagedVec::new();
+        for (i, candidate) in candidates.iter().enumerate() {
+            if top_count > 0 && counts.get(i) == top_count {
+                tied.push(candidate.clone_value());
+            }
+        }
+        if tied.len() < 2 {
+            return OptionalValue::None;
+        }
+
+        let mut name = info.name.clone();
+        name.append_bytes(b" (revote)");
+        let start_time = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let duration = self.revote_duration(info.id).get().min(self.get_max_election_duration());
+        let end_time = start_time + duration;
+        let revote_id = self.store_new_election(name, start_time, end_time, tied.clone(), info.merkle_root.clone(), None);
+
+        self.open_tally(revote_id).set(true);
+        for voter in self.eligible_voters(info.id).iter() {
+            self.copy_voter_weight(info.id, revote_id, &voter);
+            self.eligible_voters(revote_id).insert(voter);
+        }
+        for voter in self.merkle_registered(info.id).iter() {
+            self.copy_voter_weight(info.id, revote_id, &voter);
+            self.merkle_registered(revote_id).insert(voter);
+        }
+        for candidate in tied.iter() {
+            let cap_mapper = self.candidate_cap(info.id, &candidate);
+            if !cap_mapper.is_empty() {
+                self.candidate_cap(revote_id, &candidate).set(cap_mapper.get());
+            }
+        }
+        if !self.stake_contract(info.id).is_empty() {
+            self.stake_contract(revote_id).set(self.stake_contract(info.id).get());
+        }
+        self.revote_election(info.id).set(revote_id);
+
+        OptionalValue::Some(revote_id)
+    }
+
+    fn copy_voter_weight(&self, from_id: u64, to_id: u64, voter: &ManagedAddress) {
+        let weight_mapper = self.voter_weight(from_id, voter);
+        if !weight_mapper.is_empty() {
+            self.voter_weight(to_id, voter).set(weight_mapper.get());
+        }
+    }
+
+    /// Rejects votes until resumed; the voting window keeps running
+    #[endpoint(pauseElection)]
+    fn pause_election(&self, election_id: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(!self.election_info(election_id).get().is_finalized, "Election already finalized");
+
+        self.election_paused(election_id).set(true);
+    }
+
+    #[endpoint(resumeElection)]
+    fn resume_election(&self, election_id: u64) {
+        self.require_organizer();
+        require!(self.election_paused(election_id).get(), "Election not paused");
+
+        self.election_paused(election_id).clear();
+    }
+
+    /// Emergency stop for every election at once; counts and voting windows are left untouched
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.require_organizer();
+        self.paused().set(true);
+    }
+
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.require_organizer();
+        self.paused().clear();
+    }
+
+    /// Incident response: pauses every currently active election among the MAX_ELECTION_SCAN most
+    /// recent ones, which are then resumed one by one. Older active elections must be paused individually.
+    /// Returns the number of elections paused.
+    #[endpoint(pauseAllElections)]
+    fn pause_all_elections(&self) -> u64 {
+        self.require_organizer();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        let mut paused = 0u64;
+        let last_id = self.last_election_id().get();
+        let first_id = last_id.saturating_sub(MAX_ELECTION_SCAN) + 1;
+        for id in first_id..=last_id {
+            if self.election_info(id).is_empty() || self.election_paused(id).get() {
+                continue;
+            }
+            let info = self.election_info(id).get();
+            let is_active = !info.is_finalized
+                && current_timestamp >= info.start_time
+                && current_timestamp <= info.end_time;
+            if is_active {
+                self.election_paused(id).set(true);
+                paused += 1;
+            }
+        }
+        paused
+    }
+
+    /// Frees the per-voter and per-candidate tally storage of a finalized election once it can no longer
+    /// be reopened. ElectionInfo and the final results are kept, so result views keep working, while
+    /// eligibility, turnout and badge claims are gone with the cleared sets.
+    #[endpoint(purgeElection)]
+    fn purge_election(&self, election_id: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.election_info(election_id).get().is_finalized, "Election not finalized");
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(
+            current_timestamp > self.finalized_at(election_id).get() + REOPEN_WINDOW_SECONDS,
+            "Reopen window still open",
+        );
+
+        // Proven candidates and approved write-ins are tallied too, so clear counts over the whole set
+        let info = self.election_info(election_id).get();
+        for candidate in self.tally_candidates(election_id, &info).iter() {
+            self.vote_counts(election_id, &candidate).clear();
+        }
+        for candidate in self.final_candidates(election_id).get().iter() {
+            self.vote_counts(election_id, &candidate).clear();
+        }
+        self.candidates(election_id).clear();
+        self.proven_candidates(election_id).clear();
+
+        // Per-voter entries are keyed by address, so they go before the sets that list the voters
+        for voter in self.has_voted(election_id).iter() {
+            self.voter_choice(election_id, &voter).clear();
+            self.cast_weight(election_id, &voter).clear();
+            self.vote_commitment(election_id, &voter).clear();
+            self.commit_round(election_id, &voter).clear();
+        }
+        for voter in self.eligible_voters(election_id).iter() {
+            self.voter_weight(election_id, &voter).clear();
+        }
+        for voter in self.ranked_voters(election_id).iter() {
+            self.ranked_ballot(election_id, &voter).clear();
+        }
+        self.ranked_voters(election_id).clear();
+        self.revealed(election_id).clear();
+        self.encrypted_votes(election_id).clear();
+        self.used_nullifiers(election_id).clear();
+        self.merkle_registered(election_id).clear();
+        self.eligible_voters(election_id).clear();
+        self.has_voted(election_id).clear();
+    }
+
+    /// Undoes a mistaken finalization within REOPEN_WINDOW_SECONDS: final results are cleared and
+    /// voting resumes until `new_end_time`, votes already cast being kept. Not allowed once the
+    /// results were notarized or a tie revote was opened from them.
+    #[endpoint(reopenElection)]
+    fn reopen_election(&self, election_id: u64, new_end_time: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut info = self.election_info(election_id).get();
+        require!(info.is_finalized, "Election not finalized");
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(
+            current_timestamp <= self.finalized_at(election_id).get() + REOPEN_WINDOW_SECONDS,
+            "Reopen window expired",
+        );
+        require!(new_end_time > current_timestamp, "New end time must be in the future");
+        require!(info.start_time < new_end_time, "Start time must be before end time");
+        require!(new_end_time - info.start_time <= self.get_max_election_duration(), "Election too long");
+        require!(!self.results_notarized(election_id).get(), "Results already notarized");
+        require!(self.revote_election(election_id).is_empty(), "Revote already opened");
+
+        self.final_candidates(election_id).clear();
+        self.final_counts(election_id).clear();
+        self.final_eligible_count(election_id).clear();
+        self.finalized_at(election_id).clear();
+        self.quorum_met(election_id).clear();
+        self.deposits_swept(election_id).clear();
+
+        info.is_finalized = false;
+        info.end_time = new_end_time;
+        self.election_info(election_id).set(&info);
+
+        self.election_reopened_event(election_id, new_end_time);
+    }
+
+    /// Withdraws a misconfigured election before it starts. Its info, candidates and voter list are
+    /// cleared, which drops it from every listing; the id stays reserved and is reported by `isCancelled`.
+    /// A snapshot of the info is kept so the election can still be cloned with corrected settings.
+    #[endpoint(cancelElection)]
+    fn cancel_election(&self, election_id: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp < info.start_time, "Election already started");
+
+        if !self.election_group(election_id).is_empty() {
+            let group_id = self.election_group(election_id).take();
+            self.group_elections(group_id).remove(&election_id);
+        }
+        self.cancelled_info(election_id).set(&info);
+        self.election_info(election_id).clear();
+        self.candidates(election_id).clear();
+        self.eligible_voters(election_id).clear();
+        self.cancelled_elections().insert(election_id);
+    }
+
+    /// Pushes back the end of a running or pending election; end times can never be moved earlier
+    #[endpoint(extendElection)]
+    fn extend_election(&self, election_id: u64, new_end_time: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp <= info.end_time, "Election already ended");
+        require!(new_end_time > info.end_time, "New end time must be later");
+        require!(new_end_time - info.start_time <= self.get_max_election_duration(), "Election too long");
+
+        info.end_time = new_end_time;
+        self.election_info(election_id).set(&info);
+    }
+
+    #[endpoint(forceEndElection)]
+    fn force_end_election(&self, election_id: u64) {
+        self.require_organizer();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        let mut info = self.election_info(election_id).get();
+        require!(!info.is_finalized, "Election already finalized");
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        // Prevent redundant force end calls
+        require!(current_timestamp <= info.end_time, "Election already ended");
+
+        // Force end voting immediately by updating end_time to slightly in the past
+        // This ensures publish_results (which checks > end_time) works immediately
+        info.end_time = current_timestamp.saturating_sub(1);
+        self.election_info(election_id).set(&info);
+    }
+
+    #[endpoint(vote)]
+    fn vote(&self, election_id: u64, encrypted_ballot: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+
+        self.require_direct_voting(election_id, &caller);
+        self.require_not_barred_organizer(election_id, &caller);
+
+        require!(
+            self.is_eligible_voter(election_id, &caller) || !self.holds_required_token(election_id, &caller),
+            "Token holders vote with voteWithToken",
+        );
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        if self.has_voted(election_id).contains(&caller) {
+            // Last-vote-wins: the previous choice is retracted, its receipt stays in the receipt tree
+            require!(self.last_vote_wins(election_id).get(), "Already voted");
+            require!(!self.voter_choice(election_id, &caller).is_empty(), "Vote cannot be changed");
+            let previous = self.voter_choice(election_id, &caller).get();
+            let previous_weight = self.voter_cast_weight(election_id, &caller);
+            self.retract_vote(election_id, &previous, previous_weight);
+        }
+
+        self.cast_vote(election_id, &info, caller, encrypted_ballot);
+    }
+
+    /// Casts the ballot of a voter who delegated to the caller, at the voter's own weight.
+    /// The receipt, choice and event are recorded under the voter, as if they had called `vote`.
+    #[endpoint(voteAs)]
+    fn vote_as(&self, election_id: u64, on_behalf_of: ManagedAddress, encrypted_ballot: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+
+        self.require_direct_voting(election_id, &on_behalf_of);
+
+        require!(
+            !self.delegation(election_id, &on_behalf_of).is_empty()
+                && self.delegation(election_id, &on_behalf_of).get() == caller,
+            "Not the delegate",
+        );
+        self.require_not_barred_organizer(election_id, &caller);
+        self.require_not_barred_organizer(election_id, &on_behalf_of);
+        require!(self.is_eligible_voter(election_id, &on_behalf_of), "Not eligible to vote");
+        require!(!self.has_voted(election_id).contains(&on_behalf_of), "Already voted");
+
+        self.cast_vote(election_id, &info, on_behalf_of, encrypted_ballot);
+    }
+
+    /// `vote` for holders of the election's required token, in place of the eligibility list
+    #[endpoint(voteWithToken)]
+    fn vote_with_token(&self, election_id: u64, encrypted_ballot: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(!self.required_token(election_id).is_empty(), "Election has no required token");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+
+        self.require_direct_voting(election_id, &caller);
+
+        self.require_not_barred_organizer(election_id, &caller);
+
+        require!(self.holds_required_token(election_id, &caller), "Required token not held");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        self.cast_vote(election_id, &info, caller, encrypted_ballot);
+    }
+
+    /// Tail shared by `vote`, `voteAs` and `voteWithToken`, once every check has passed
+    fn cast_vote(&self, election_id: u64, info: &ElectionInfo<Self::Api>, voter: ManagedAddress, encrypted_ballot: ManagedBuffer) {
+        let weight = self.vote_weight(election_id, &voter);
+        self.record_vote_receipt(election_id, voter.as_managed_buffer(), &encrypted_ballot);
+        if self.open_tally(election_id).get() {
+            self.voter_choice(election_id, &voter).set(&encrypted_ballot);
+            self.cast_weight(election_id, &voter).set(weight);
+        }
+        // The voter stays out of the topics, so indexers cannot filter votes by address
+        self.vote_cast_event(election_id, &encrypted_ballot, &voter);
+        self.record_ballot(election_id, info, encrypted_ballot, weight);
+        
+        // Record that this voter has voted
+        self.has_voted(election_id).insert(voter);
+    }
+
+    /// Moves the caller's open-tally `vote` to `new_candidate` while voting is open, keeping its weight.
+    /// The new choice gets its own receipt; the old receipt stays in the receipt tree.
+    #[endpoint(changeVote)]
+    fn change_vote(&self, election_id: u64, new_candidate: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+
+        require!(self.has_voted(election_id).contains(&caller), "Not voted yet");
+        require!(!self.voter_choice(election_id, &caller).is_empty(), "Vote cannot be changed");
+        let previous = self.voter_choice(election_id, &caller).get();
+        require!(previous != new_candidate, "Same candidate");
+
+        let weight = self.voter_cast_weight(election_id, &caller);
+        self.retract_vote(election_id, &previous, weight);
+        self.record_vote_receipt(election_id, caller.as_managed_buffer(), &new_candidate);
+        self.voter_choice(election_id, &caller).set(&new_candidate);
+        self.vote_cast_event(election_id, &new_candidate, &caller);
+        self.record_ballot(election_id, &info, new_candidate, weight);
+    }
+
+    /// Records a formal abstention: the caller counts as having voted but adds to no candidate
+    #[endpoint(abstain)]
+    fn abstain(&self, election_id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+
+        self.require_direct_voting(election_id, &caller);
+        self.require_not_barred_organizer(election_id, &caller);
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        self.abstain_count(election_id).update(|count| *count += 1);
+        self.has_voted(election_id).insert(caller);
+    }
+
+    /// Same as `vote`, but returns false instead of reverting when the election is not active or the
+    /// caller is ineligible, has no resolvable vote weight, has delegated or has already voted.
+    /// Invalid ballots still revert.
+    #[endpoint(tryVote)]
+    fn try_vote(&self, election_id: u64, candidate: ManagedBuffer) -> bool {
+        let caller = self.blockchain().get_caller();
+        if self.election_info(election_id).is_empty() {
+            return false;
+        }
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let is_active = !info.is_finalized
+            && !self.paused().get()
+            && !self.election_paused(election_id).get()
+            && current_timestamp >= info.start_time
+            && current_timestamp <= info.end_time;
+        let can_vote_again = !self.has_voted(election_id).contains(&caller) || self.last_vote_wins(election_id).get();
+        let is_barred = self.is_barred_organizer(election_id, &caller);
+        let has_delegated = !self.delegation(election_id, &caller).is_empty();
+        if !is_active || is_barred || has_delegated || !self.is_eligible_voter(election_id, &caller) || !can_vote_again {
+            return false;
+        }
+        if self.checked_vote_weight(election_id, &caller).is_err() {
+            return false;
+        }
+
+        self.vote(election_id, candidate);
+        true
+    }
+
+    #[endpoint(voteWithMerkle)]
+    fn vote_with_merkle(
+        &self,
+        election_id: u64,
+        nullifier: ManagedBuffer,
+        encrypted_ballot: ManagedBuffer,
+        merkle_proof: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+        require!(
+            self.get_voting_mode(election_id) == VotingMode::MerkleProof,
+            "Election not configured for Merkle voting",
+        );
+        require!(info.encryption_public_key.is_some(), "Election encryption keys not set");
+        self.require_not_barred_organizer(election_id, &caller);
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        // Nullifier prevents double voting without storing voter address
+        require!(
+            !self.used_nullifiers(election_id).contains(&nullifier),
+            "Already voted",
+        );
+
+        // Verify Merkle membership of caller
+        let leaf = self.hash_address(&caller);
+        let is_valid = self.verify_merkle_proof_leaf(
+            &leaf,
+            &info.merkle_root.unwrap(),
+            &merkle_proof,
+        );
+        require!(is_valid, "Invalid Merkle proof - not eligible");
+
+        self.record_vote_receipt(election_id, &nullifier, &encrypted_ballot);
+        self.vote_cast_event(election_id, &encrypted_ballot, &caller);
+        self.used_nullifiers(election_id).insert(nullifier);
+        self.encrypted_votes(election_id).insert(encrypted_ballot);
+    }
+
+    /// Anonymous voting: the anonymous root commits to keccak256(nullifier) instead of voter addresses,
+    /// so the ballot can be submitted from any account (e.g. a relayer) without linking it to the voter.
+    /// Proving knowledge of the nullifier in zero knowledge is out of scope for now.
+    #[endpoint(voteAnonymous)]
+    fn vote_anonymous(
+        &self,
+        election_id: u64,
+        ballot: ManagedBuffer,
+        nullifier: ManagedBuffer,
+        merkle_proof: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+        require!(!self.anonymous_root(election_id).is_empty(), "Anonymous voting not enabled");
+        // Nullifier ballots have no voter to match against a Merkle election's list
+        if let Some(reason) = self.voting_mode_blocker(election_id) {
+            sc_panic!(reason);
+        }
+        require!(!nullifier.is_empty(), "Nullifier cannot be empty");
+
+        require!(
+            !self.used_nullifiers(election_id).contains(&nullifier),
+            "Already voted",
+        );
+
+        let leaf = self.hash_leaf(&nullifier);
+        let is_valid = self.verify_merkle_proof_leaf(
+            &leaf,
+            &self.anonymous_root(election_id).get(),
+            &merkle_proof,
+        );
+        require!(is_valid, "Invalid Merkle proof - not eligible");
+
+        self.record_vote_receipt(election_id, &nullifier, &ballot);
+        self.vote_cast_event(election_id, &ballot, &self.blockchain().get_caller());
+        self.used_nullifiers(election_id).insert(nullifier);
+        self.record_ballot(election_id, &info, ballot, 1);
+    }
+
+    /// Threshold-encrypted elections store the ElGamal ciphertext from the client;
+    /// open-tally elections are tallied in the clear, the ballot being the candidate name.
+    fn record_ballot(&self, election_id: u64, info: &ElectionInfo<Self::Api>, ballot: ManagedBuffer, weight: u64) {
+        if info.encryption_public_key.is_some() {
+            self.encrypted_votes(election_id).insert(ballot);
+            self.total_weighted_votes(election_id).update(|total| *total += 1);
+            return;
+        }
+        require!(self.open_tally(election_id).get(), "Election encryption keys not set");
+
+        require!(
+            self.candidates(election_id).contains(&ballot) || self.proven_candidates(election_id).contains(&ballot),
+            "Invalid candidate",
+        );
+        require!(!self.disqualified(election_id).contains(&ballot), "Candidate disqualified");
+
+        let cap_mapper = self.candidate_cap(election_id, &ballot);
+        if !cap_mapper.is_empty() {
+            require!(self.vote_counts(election_id, &ballot).get() < cap_mapper.get(), "Candidate is full");
+        }
+
+        self.add_vote(election_id, &ballot, weight);
+    }
+
+    /// Weights can be large, so the tally reverts instead of wrapping around
+    fn add_vote(&self, election_id: u64, candidate: &ManagedBuffer, weight: u64) {
+        let (Some(count), Some(total)) = (
+            self.vote_counts(election_id, candidate).get().checked_add(weight),
+            self.total_weighted_votes(election_id).get().checked_add(weight),
+        ) else {
+            sc_panic!("Vote count overflow");
+        };
+        self.vote_counts(election_id, candidate).set(count);
+        self.total_weighted_votes(election_id).set(total);
+    }
+
+    /// Weight of a voter's ballot in every endpoint that knows the voter: the tier weight of their
+    /// registered NFT in NFT-gated elections, else their stake when the election has a staking contract,
+    /// else their listed weight (1 when none was set). A missing NFT, an empty stake or a failing stake
+    /// query rejects the vote instead of silently counting 1. Nullifier ballots count 1.
+    fn vote_weight(&self, election_id: u64, voter: &ManagedAddress) -> u64 {
+        match self.checked_vote_weight(election_id, voter) {
+            Ok(weight) => weight,
+            Err(reason) => sc_panic!(reason),
+        }
+    }
+
+    /// `vote_weight` without reverting, so `canVote` can report why a vote would fail
+    fn checked_vote_weight(&self, election_id: u64, voter: &ManagedAddress) -> Result<u64, &'static str> {
+        if !self.nft_gate(election_id).is_empty() {
+            if self.voting_nft(election_id, voter).is_empty() {
+                return Err("No qualifying NFT");
+            }
+            return self.nft_tier_weight(election_id, voter, self.voting_nft(election_id, voter).get());
+        }
+        if self.stake_contract(election_id).is_empty() && !self.voter_weight(election_id, voter).is_empty() {
+            return Ok(self.voter_weight(election_id, voter).get());
+        }
+        self.stake_weight(election_id, voter)
+    }
+
+    fn nft_tier_weight(&self, election_id: u64, voter: &ManagedAddress, nonce: u64) -> Result<u64, &'static str> {
+        if nonce == 0 {
+            return Err("No qualifying NFT");
+        }
+        let token_data = self.blockchain().get_esdt_token_data(voter, &self.nft_gate(election_id).get(), nonce);
+        if token_data.amount == 0 || token_data.attributes.is_empty() {
+            return Err("No qualifying NFT");
+        }
+
+        let mut tier = [0u8; 1];
+        token_data.attributes.load_slice(0, &mut tier);
+        let weight = self.tier_weight(election_id, tier[0]).get();
+        if weight == 0 {
+            return Err("No qualifying NFT");
+        }
+        Ok(weight)
+    }
+
+    fn stake_weight(&self, election_id: u64, voter: &ManagedAddress) -> Result<u64, &'static str> {
+        if self.stake_contract(election_id).is_empty() {
+            return Ok(1);
+        }
+
+        let stake_result = self
+            .tx()
+            .to(self.stake_contract(election_id).get())
+            .raw_call("getStake")
+            .argument(voter)
+            .original_result::<BigUint>()
+            .returns(ReturnsHandledOrError::new().returns(ReturnsResult))
+            .sync_call_fallible();
+        let Ok(stake) = stake_result else {
+            return Err("Stake query failed");
+        };
+        if stake == 0 {
+            return Err("No stake");
+        }
+        stake.to_u64().ok_or("Stake too large")
+    }
+
+    /// Weight the voter's ballot was counted with; ballots cast before weights were tracked count 1
+    fn voter_cast_weight(&self, election_id: u64, voter: &ManagedAddress) -> u64 {
+        if self.cast_weight(election_id, voter).is_empty() {
+            1
+        } else {
+            self.cast_weight(election_id, voter).get()
+        }
+    }
+
+    /// Removes up to `weight` votes; votes already voided by disqualification are not removed twice
+    fn retract_vote(&self, election_id: u64, candidate: &ManagedBuffer, weight: u64) {
+        let counted = self.vote_counts(election_id, candidate).get().min(weight);
+        self.vote_counts(election_id, candidate).update(|count| *count -= counted);
+        let total = self.total_weighted_votes(election_id).get().checked_sub(counted);
+        require!(total.is_some(), "Vote count underflow");
+        self.total_weighted_votes(election_id).set(total.unwrap_or_default());
+    }
+
+    /// Receipt of a single vote: keccak256(len || voter || ballot), the voter being the caller's address,
+    /// or the nullifier for Merkle votes. Voters recompute it client-side to check inclusion.
+    fn record_vote_receipt(&self, election_id: u64, voter: &ManagedBuffer, ballot: &ManagedBuffer) {
+        let mut preimage = ManagedBuffer::new();
+        preimage.append_u32_be(voter.len() as u32);
+        preimage.append(voter);
+        preimage.append(ballot);
+        let receipt = self.hash_leaf(&preimage);
+
+        // Incremental Merkle tree: only the left sibling of each level's rightmost path is kept
+        let index = self.votes_tree_size(election_id).get();
+        require!(index < 1u64 << VOTES_TREE_DEPTH, "Vote receipt tree is full");
+
+        let mut node = receipt;
+        let mut position = index;
+        for level in 0..VOTES_TREE_DEPTH {
+            if position & 1 == 0 {
+                self.votes_tree_branch(election_id, level).set(node);
+                break;
+            }
+            node = self.hash_sorted_pair(&self.votes_tree_branch(election_id, level).get(), &node);
+            position >>= 1;
+        }
+        self.votes_tree_size(election_id).set(index + 1);
+    }
+
+    /// The commitment is keccak256(candidate || salt); the voter counts as having voted from here on,
+    /// at their weight as of the commit. Elections with a reveal deposit require exactly that EGLD amount,
+    /// refunded on reveal.
+    #[payable("EGLD")]
+    #[endpoint(commitVote)]
+    fn commit_vote(&self, election_id: u64, commitment: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+        require!(
+            self.get_voting_mode(election_id) == VotingMode::CommitReveal,
+            "Election does not use commit-reveal",
+        );
+        require!(commitment.len() == 32, "Commitment must be 32 bytes (keccak256)");
+
+        self.require_not_barred_organizer(election_id, &caller);
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+        require!(
+            *self.call_value().egld() == self.reveal_deposit(election_id).get(),
+            "Payment must equal the reveal deposit",
+        );
+
+        let weight = self.vote_weight(election_id, &caller);
+        self.vote_commitment(election_id, &caller).set(commitment);
+        self.cast_weight(election_id, &caller).set(weight);
+        self.commit_round(election_id, &caller).set(self.sweep_round(election_id).get());
+        self.commit_count(election_id).update(|count| *count += 1);
+        self.held_deposits(election_id).update(|held| *held += 1);
+        self.has_voted(election_id).insert(caller);
+    }
+
+    #[endpoint(revealVote)]
+    fn reveal_vote(&self, election_id: u64, candidate: ManagedBuffer, salt: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp > info.end_time, "Reveal window not open");
+        require!(
+            current_timestamp <= info.end_time + self.reveal_duration(election_id).get(),
+            "Reveal window closed",
+        );
+        require!(!self.vote_commitment(election_id, &caller).is_empty(), "No commitment");
+        require!(!self.revealed(election_id).contains(&caller), "Already revealed");
+
+        let mut preimage = candidate.clone();
+        preimage.append(&salt);
+        require!(
+            self.hash_leaf(&preimage) == self.vote_commitment(election_id, &caller).get(),
+            "Reveal does not match commitment",
+        );
+
+        self.record_vote_receipt(election_id, caller.as_managed_buffer(), &candidate);
+        self.vote_cast_event(election_id, &candidate, &caller);
+        self.record_ballot(election_id, &info, candidate, self.voter_cast_weight(election_id, &caller));
+        self.revealed(election_id).insert(caller.clone());
+
+        // A deposit forfeited by an earlier sweep is gone; revealing after a reopen only counts the vote
+        if self.commit_round(election_id, &caller).get() != self.sweep_round(election_id).get() {
+            return;
+        }
+        self.held_deposits(election_id).update(|held| *held -= 1);
+        let deposit = self.reveal_deposit(election_id).get();
+        if deposit > 0 {
+            self.tx().to(&caller).egld(&deposit).transfer();
+        }
+    }
+
+    /// After the reveal window, forfeits the deposits still held for unrevealed commitments to the
+    /// organizer that configured the deposit. Anyone may trigger it, so deposits are not locked once
+    /// the organizer renounces.
+    #[endpoint(sweepUnrevealed)]
+    fn sweep_unrevealed(&self, election_id: u64) -> BigUint {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        require!(
+            current_timestamp > info.end_time + self.reveal_duration(election_id).get(),
+            "Reveal window still open",
+        );
+        require!(!self.deposits_swept(election_id).get(), "Deposits already swept");
+
+        let no_shows = self.held_deposits(election_id).take();
+        let forfeited = self.reveal_deposit(election_id).get() * no_shows;
+        self.deposits_swept(election_id).set(true);
+        self.sweep_round(election_id).update(|round| *round += 1);
+        if forfeited > 0 {
+            self.tx().to(&self.deposit_beneficiary(election_id).get()).egld(&forfeited).transfer();
+        }
+        forfeited
+    }
+
+    /// NFT collection badges are minted from; the contract needs the ESDTNFTCreate role on it
+    #[endpoint(setBadgeCollection)]
+    fn set_badge_collection(&self, collection: EsdtTokenIdentifier) {
+        self.require_organizer();
+        require!(collection.is_valid_esdt_identifier(), "Invalid collection");
+        self.badge_collection().set(collection);
+    }
+
+    /// Mints the caller a commemorative badge for a finalized election they voted in, once per voter.
+    /// The badge's attributes hold the election id. Returns the badge nonce.
+    #[endpoint(claimBadge)]
+    fn claim_badge(&self, election_id: u64) -> u64 {
+        let caller = self.blockchain().get_caller();
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(!self.badge_collection().is_empty(), "Badges not enabled");
+
+        let info = self.election_info(election_id).get();
+        require!(info.is_finalized, "Election not finalized");
+        require!(self.has_voted(election_id).contains(&caller), "Did not vote");
+        require!(!self.badge_claimed(election_id, &caller).get(), "Badge already claimed");
+
+        self.badge_claimed(election_id, &caller).set(true);
+        let collection = self.badge_collection().get();
+        let one = BigUint::from(1u32);
+        let nonce = self.send().esdt_nft_create_compact_named(&collection, &one, &info.name, &election_id);
+        self.tx().to(&caller).single_esdt(&collection, nonce, &one).transfer();
+        nonce
+    }
+
+    /// Hands the caller's voting weight to `delegate_to` for this election; the cap counts that weight
+    /// as of delegation, since `voteAs` casts the ballot at the delegator's own weight
+    #[endpoint(delegate)]
+    fn delegate(&self, election_id: u64, delegate_to: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(caller != delegate_to, "Cannot delegate to self");
+
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+        require!(self.delegation(election_id, &caller).is_empty(), "Already delegated");
+
+        let weight = self.vote_weight(election_id, &caller);
+        let Some(power) = self.delegated_power(election_id, &delegate_to).get().checked_add(weight) else {
+            sc_panic!("Delegate weight cap exceeded");
+        };
+        let max_weight = self.max_delegated_weight(election_id).get();
+        require!(max_weight == 0 || power <= max_weight, "Delegate weight cap exceeded");
+
+        self.delegated_power(election_id, &delegate_to).set(power);
+        self.delegation(election_id, &caller).set(delegate_to);
+    }
+
+    /// Each selected candidate gets the voter's full weight and its own receipt; the voter is marked once
+    #[endpoint(voteMulti)]
+    fn vote_multi(&self, election_id: u64, candidates: MultiValueEncoded<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_APPROVAL);
+        self.require_direct_voting(election_id, &caller);
+
+        let max_choices = self.max_choices(election_id).get();
+        require!(max_choices > 0, "Multi-choice voting not enabled");
+        require!(!candidates.is_empty(), "No candidates selected");
+        require!(candidates.len() <= max_choices as usize, "Too many candidates selected");
+
+        self.require_not_barred_organizer(election_id, &caller);
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        let weight = self.vote_weight(election_id, &caller);
+        let mut selected = ManagedVec::<Self::Api, ManagedBuffer>::new();
+        for candidate in candidates {
+            require!(!selected.contains(&candidate), "Duplicate candidate");
+            self.record_vote_receipt(election_id, caller.as_managed_buffer(), &candidate);
+            self.vote_cast_event(election_id, &candidate, &caller);
+            self.record_ballot(election_id, &info, candidate.clone(), weight);
+            selected.push(candidate);
+        }
+
+        self.has_voted(election_id).insert(caller);
+    }
+
+    /// Stores an ordered (possibly partial) preference list at the voter's weight; the first preference
+    /// also counts in the regular tally, so live results show first-preference totals
+    #[endpoint(voteRanked)]
+    fn vote_ranked(&self, election_id: u64, ranking: MultiValueEncoded<ManagedBuffer>) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_RANKED);
+        require!(
+            self.get_voting_mode(election_id) == VotingMode::RankedChoice,
+            "Election does not use ranked ballots",
+        );
+        require!(!ranking.is_empty(), "Ranking cannot be empty");
+        require!(self.ranked_voters(election_id).len() < MAX_RANKED_BALLOTS, "Ranked ballot limit reached");
+
+        self.require_not_barred_organizer(election_id, &caller);
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        let mut ballot = ManagedVec::<Self::Api, ManagedBuffer>::new();
+        let mut encoded = ManagedBuffer::new();
+        for candidate in ranking {
+            require!(self.candidates(election_id).contains(&candidate), "Invalid candidate");
+            require!(!ballot.contains(&candidate), "Duplicate candidate");
+            encoded.append_u32_be(candidate.len() as u32);
+            encoded.append(&candidate);
+            ballot.push(candidate);
+        }
+
+        let weight = self.vote_weight(election_id, &caller);
+        let first_preference = ballot.get(0).clone_value();
+        self.record_vote_receipt(election_id, caller.as_managed_buffer(), &encoded);
+        self.vote_cast_event(election_id, &first_preference, &caller);
+        self.record_ballot(election_id, &info, first_preference, weight);
+        self.cast_weight(election_id, &caller).set(weight);
+        self.ranked_ballot(election_id, &caller).set(&ballot);
+        self.ranked_voters(election_id).push(&caller);
+        self.has_voted(election_id).insert(caller);
+    }
+
+    /// Votes for a name that is not on the ballot; it is counted only once the organizer approves it
+    #[endpoint(voteWriteIn)]
+    fn vote_write_in(&self, election_id: u64, name: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+        self.require_direct_voting(election_id, &caller);
+        require!(self.write_ins_enabled(election_id).get(), "Write-ins not enabled");
+        self.require_valid_candidate_name(&name);
+
+        self.require_not_barred_organizer(election_id, &caller);
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        let weight = self.vote_weight(election_id, &caller);
+        self.record_vote_receipt(election_id, caller.as_managed_buffer(), &name);
+        self.vote_cast_event(election_id, &name, &caller);
+        if self.candidates(election_id).contains(&name) {
+            self.record_ballot(election_id, &info, name, weight);
+        } else {
+            let Some(votes) = self.write_in_votes(election_id, &name).get().checked_add(weight) else {
+                sc_panic!("Vote count overflow");
+            };
+            self.pending_write_ins(election_id).insert(name.clone());
+            self.write_in_votes(election_id, &name).set(votes);
+        }
+
+        self.has_voted(election_id).insert(caller);
+    }
+
+    /// Votes for a candidate proven against the election's candidate root, without the candidate
+    /// having been registered. Only candidates that actually receive votes are ever stored.
+    #[endpoint(voteForProvenCandidate)]
+    fn vote_for_proven_candidate(
+        &self,
+        election_id: u64,
+        candidate: ManagedBuffer,
+        proof: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let caller = self.blockchain().get_caller();
+
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+
+        require!(current_timestamp >= info.start_time, "Election not started");
+        require!(current_timestamp <= info.end_time, "Election ended");
+        require!(!info.is_finalized, "Election finalized");
+        require!(!self.paused().get(), "Contract is paused");
+        require!(!self.election_paused(election_id).get(), "Election paused");
+        self.require_method_allowed(election_id, METHOD_SINGLE_CHOICE);
+        self.require_direct_voting(election_id, &caller);
+        require!(self.open_tally(election_id).get(), "Proven candidates require an open-tally election");
+        require!(!self.candidate_root(election_id).is_empty(), "Election has no candidate allowlist");
+
+        self.require_not_barred_organizer(election_id, &caller);
+        require!(self.is_eligible_voter(election_id, &caller), "Not eligible to vote");
+        require!(self.delegation(election_id, &caller).is_empty(), "Vote delegated");
+        require!(!self.has_voted(election_id).contains(&caller), "Already voted");
+
+        let leaf = self.hash_leaf(&candidate);
+        let is_valid = self.verify_merkle_proof_leaf(
+            &leaf,
+            &self.candidate_root(election_id).get(),
+            &proof,
+        );
+        require!(is_valid, "Invalid Merkle proof - candidate not allowed");
+
+        let weight = self.vote_weight(election_id, &caller);
+        if !self.candidates(election_id).contains(&candidate) {
+            self.proven_candidates(election_id).insert(candidate.clone());
+        }
+        self.record_vote_receipt(election_id, caller.as_managed_buffer(), &candidate);
+        self.vote_cast_event(election_id, &candidate, &caller);
+        self.record_ballot(election_id, &info, candidate, weight);
+
+        self.has_voted(election_id).insert(caller);
+    }
+
+    /// Registered candidates in ballot order, followed by proven candidates that received votes.
+    /// Approved write-ins and newly proven candidates join mid-election and can shift positions,
+    /// so snapshots keep candidate names next to their counts.
+    fn tally_candidates(&self, election_id: u64, info: &ElectionInfo<Self::Api>) -> ManagedVec<ManagedBuffer> {
+        let mut result = self.ordered_candidates(election_id, info);
+        for candidate in self.proven_candidates(election_id).iter() {
+            result.push(candidate);
+        }
+        result
+    }
+
+    #[view(verifyMerkleProof)]
+    fn verify_merkle_proof(
+        &self,
+        voter: &ManagedAddress,
+        merkle_root: &ManagedBuffer,
+        proof: &MultiValueEncoded<ManagedBuffer>,
+    ) -> bool {
+        let leaf = self.hash_address(voter);
+        self.verify_merkle_proof_leaf(&leaf, merkle_root, proof)
+    }
+
+    fn is_eligible_voter(&self, election_id: u64, voter: &ManagedAddress) -> bool {
+        self.eligible_voters(election_id).contains(voter) || self.merkle_registered(election_id).contains(voter)
+    }
+
+    /// Whether the voter holds a positive balance of the election's required token; false when none is set
+    fn holds_required_token(&self, election_id: u64, voter: &ManagedAddress) -> bool {
+        if self.required_token(election_id).is_empty() {
+            return false;
+        }
+        let token = self.required_token(election_id).get();
+        // Token data rather than `get_esdt_balance`, which the scenario VM only serves for the contract itself
+        self.blockchain().get_esdt_token_data(voter, &token, 0).amount > 0u64
+    }
+
+    /// Eligible through the list, a Merkle registration or the required token
+    fn has_voting_access(&self, election_id: u64, voter: &ManagedAddress) -> bool {
+        self.is_eligible_voter(election_id, voter) || self.holds_required_token(election_id, voter)
+    }
+
+    fn hash_address(&self, addr: &ManagedAddress) -> ManagedBuffer {
+        self.hash_leaf(addr.as_managed_buffer())
+    }
+
+    fn hash_leaf(&self, data: &ManagedBuffer) -> ManagedBuffer {
+        let hash = self.crypto().keccak256(data);
+        ManagedBuffer::new_from_bytes(&hash.to_byte_array())
+    }
+
+    /// Commutative node hash, so proofs need no left/right path bits
+    fn hash_sorted_pair(&self, a: &ManagedBuffer, b: &ManagedBuffer) -> ManagedBuffer {
+        let mut combined = ManagedBuffer::new();
+        if self.compare_buffers(a, b) == core::cmp::Ordering::Greater {
+            combined.append(b);
+            combined.append(a);
+        } else {
+            combined.append(a);
+            combined.append(b);
+        }
+        self.hash_leaf(&combined)
+    }
+
+    fn verify_merkle_proof_leaf(
+        &self,
+        leaf: &ManagedBuffer,
+        merkle_root: &ManagedBuffer,
+        proof: &MultiValueEncoded<ManagedBuffer>,
+    ) -> bool {
+        let mut current_buffer = leaf.clone();
+
+        // Pairs are sorted before hashing, as in merkletreejs with `sortPairs: true`
+        for proof_element in proof.clone() {
+            require!(proof_element.len() == 32, "Proof element must be 32 bytes");
+            current_buffer = self.hash_sorted_pair(&current_buffer, &proof_element);
+        }
+
+        &current_buffer == merkle_root
+    }
+
+    #[view(getAllElections)]
+    fn get_all_elections(&self) -> MultiValueEncoded<ElectionInfo<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let last_id = self.last_election_id().get();
+        for id in 1..=last_id {
+            if self.election_info(id).is_empty() {
+                continue;
+            }
+            let info = self.election_info(id).get();
+            result.push(info);
+        }
+        result
+    }
+
+    #[view(getElectionInfo)]
+    fn get_election_info(&self, election_id: u64) -> ElectionInfo<Self::Api> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        self.election_info(election_id).get()
+    }
+
+    /// MerkleProof or DirectVoting from creation; `setRevealWindow` and `setRankedChoice` switch a direct
+    /// election to CommitReveal or RankedChoice.
+    /// Elections created before the mode was stored report the mode implied by their Merkle root and flags.
+    #[view(getVotingMode)]
+    fn get_voting_mode(&self, election_id: u64) -> VotingMode {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        self.stored_voting_mode(election_id, &self.election_info(election_id).get())
+    }
+
+    fn stored_voting_mode(&self, election_id: u64, info: &ElectionInfo<Self::Api>) -> VotingMode {
+        if self.voting_mode(election_id).is_empty() {
+            if info.merkle_root.is_some() {
+                return VotingMode::MerkleProof;
+            }
+            if self.reveal_duration(election_id).get() > 0 {
+                return VotingMode::CommitReveal;
+            }
+            if self.ranked_choice(election_id).get() {
+                return VotingMode::RankedChoice;
+            }
+            return VotingMode::DirectVoting;
+        }
+        self.voting_mode(election_id).get()
+    }
+
+    /// Highest election id assigned so far, the upper bound for `getElectionsPaged`.
+    /// Cancelled ids are not reused, so it can exceed the number of stored elections.
+    #[view(getElectionCount)]
+    fn get_election_count(&self) -> u64 {
+        self.last_election_id().get()
+    }
+
+    /// At most `limit` elections with ids from `start_id` upwards, clamped to the last id; missing ids are skipped
+    #[view(getElectionsPaged)]
+    fn get_elections_paged(&self, start_id: u64, limit: u64) -> MultiValueEncoded<ElectionInfo<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let last_id = self.last_election_id().get();
+        let mut returned = 0u64;
+        for id in start_id.max(1)..=last_id {
+            if returned == limit {
+                break;
+            }
+            if self.election_info(id).is_empty() {
+                continue;
+            }
+            result.push(self.election_info(id).get());
+            returned += 1;
+        }
+        result
+    }
+
+    /// Pending elections (not yet started) ordered by start time, earliest first, at most `limit` of them.
+    /// Only the most recent `MAX_ELECTION_SCAN` elections are considered, which also bounds the sort.
+    #[view(getUpcomingElections)]
+    fn get_upcoming_elections(&self, limit: u64) -> MultiValueEncoded<ElectionInfo<Self::Api>> {
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let mut starts = ManagedVec::<Self::Api, u64>::new();
+        let mut ids = ManagedVec::<Self::Api, u64>::new();
+
+        let last_id = self.last_election_id().get();
+        let first_id = last_id.saturating_sub(MAX_ELECTION_SCAN) + 1;
+        for id in first_id..=last_id {
+            if self.election_info(id).is_empty() {
+                continue;
+            }
+            let start_time = self.election_info(id).get().start_time;
+            if current_timestamp >= start_time {
+                continue;
+            }
+
+            // Insertion sort; equal start times keep id order
+            starts.push(start_time);
+            ids.push(id);
+            let mut j = starts.len() - 1;
+            while j > 0 && starts.get(j - 1) > start_time {
+                let _ = starts.set(j, starts.get(j - 1));
+                let _ = ids.set(j, ids.get(j - 1));
+                j -= 1;
+            }
+            let _ = starts.set(j, start_time);
+            let _ = ids.set(j, id);
+        }
+
+        let mut result = MultiValueEncoded::new();
+        for id in ids.iter().take(limit as usize) {
+            result.push(self.election_info(id).get());
+        }
+        result
+    }
+
+    /// Returns (pending, active, finalized) election counts in a single pass.
+    /// Ended elections awaiting results count as active until finalized.
+    /// Still O(n) over all elections; large deployments should page through `getAllElections` instead.
+    #[view(getStateCounts)]
+    fn get_state_counts(&self) -> MultiValue3<u64, u64, u64> {
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let mut pending = 0u64;
+        let mut active = 0u64;
+        let mut finalized = 0u64;
+
+        let last_id = self.last_election_id().get();
+        for id in 1..=last_id {
+            if self.election_info(id).is_empty() {
+                continue;
+            }
+            let info = self.election_info(id).get();
+            if info.is_finalized {
+                finalized += 1;
+            } else if current_timestamp < info.start_time {
+                pending += 1;
+            } else {
+                active += 1;
+            }
+        }
+
+        (pending, active, finalized).into()
+    }
+
+    /// Counts the most recent `MAX_ELECTION_SCAN` elections by the voting method they were configured with
+    #[view(getElectionMethodStats)]
+    fn get_election_method_stats(&self) -> ElectionMethodStats {
+        let mut stats = ElectionMethodStats {
+            single_choice: 0,
+            multi_choice: 0,
+            ranked: 0,
+            commit_reveal: 0,
+            encrypted: 0,
+        };
+
+        let last_id = self.last_election_id().get();
+        let first_id = last_id.saturating_sub(MAX_ELECTION_SCAN) + 1;
+        for id in first_id..=last_id {
+            if self.election_info(id).is_empty() {
+                continue;
+            }
+            if self.election_info(id).get().encryption_public_key.is_some() {
+                stats.encrypted += 1;
+            } else if self.ranked_choice(id).get() {
+                stats.ranked += 1;
+            } else if self.reveal_duration(id).get() > 0 {
+                stats.commit_reveal += 1;
+            } else if self.max_choices(id).get() > 1 {
+                stats.multi_choice += 1;
+            } else {
+                stats.single_choice += 1;
+            }
+        }
+
+        stats
+    }
+
+    #[view(getElectionDefaults)]
+    fn get_election_defaults(&self) -> OptionalValue<ElectionDefaults> {
+        if self.election_defaults().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.election_defaults().get())
+        }
+    }
+
+    #[view(getContractConfig)]
+    fn get_contract_config(&self) -> ContractConfig<Self::Api> {
+        ContractConfig {
+            organizer: self.organizer().get(),
+            election_count: self.last_election_id().get(),
+            max_migration_elections: MAX_MIGRATION_ELECTIONS,
+            paused: self.paused().get(),
+            max_election_duration: self.get_max_election_duration(),
+            min_candidates: MIN_CANDIDATES as u64,
+            max_candidates: MAX_CANDIDATES as u64,
+            organizer_renounced: self.organizer_renounced().get(),
+            pending_organizer: self.get_pending_organizer().into_option(),
+            election_defaults: self.get_election_defaults().into_option(),
+        }
+    }
+
+    /// Ids of currently active elections where the voter is eligible and has not voted yet,
+    /// scanning at most MAX_ELECTION_SCAN of the most recent elections
+    #[view(getActiveElectionsForVoter)]
+    fn get_active_elections_for_voter(&self, voter: ManagedAddress) -> MultiValueEncoded<u64> {
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let mut result = MultiValueEncoded::new();
+
+        let last_id = self.last_election_id().get();
+        let first_id = last_id.saturating_sub(MAX_ELECTION_SCAN) + 1;
+        for id in first_id..=last_id {
+            if self.election_info(id).is_empty() {
+                continue;
+            }
+            let info = self.election_info(id).get();
+            let is_active = !info.is_finalized
+                && current_timestamp >= info.start_time
+                && current_timestamp <= info.end_time;
+            if is_active && self.has_voting_access(id, &voter) && !self.has_voted(id).contains(&voter) {
+                result.push(id);
+            }
+        }
+        result
+    }
+
+    /// (elections voted in, elections eligible for) for the voter,
+    /// scanning at most MAX_ELECTION_SCAN of the most recent elections
+    #[view(getParticipationRate)]
+    fn get_participation_rate(&self, voter: ManagedAddress) -> MultiValue2<u64, u64> {
+        let mut voted = 0u64;
+        let mut eligible = 0u64;
+
+        let last_id = self.last_election_id().get();
+        let first_id = last_id.saturating_sub(MAX_ELECTION_SCAN) + 1;
+        for id in first_id..=last_id {
+            if self.election_info(id).is_empty() || !self.has_voting_access(id, &voter) {
+                continue;
+            }
+            eligible += 1;
+            if self.has_voted(id).contains(&voter) {
+                voted += 1;
+            }
+        }
+        (voted, eligible).into()
+    }
+
+    #[view(getDelegatedPower)]
+    fn get_delegated_power(&self, election_id: u64, delegate: ManagedAddress) -> u64 {
+        self.delegated_power(election_id, &delegate).get()
+    }
+
+    /// Ids of elections in their reveal window where the voter committed but has not revealed yet,
+    /// scanning at most MAX_ELECTION_SCAN of the most recent elections
+    #[view(getElectionsRequiringReveal)]
+    fn get_elections_requiring_reveal(&self, voter: ManagedAddress) -> MultiValueEncoded<u64> {
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let mut result = MultiValueEncoded::new();
+
+        let last_id = self.last_election_id().get();
+        let first_id = last_id.saturating_sub(MAX_ELECTION_SCAN) + 1;
+        for id in first_id..=last_id {
+            if self.vote_commitment(id, &voter).is_empty() || self.revealed(id).contains(&voter) {
+                continue;
+            }
+            let info = self.election_info(id).get();
+            let in_reveal_window = current_timestamp > info.end_time
+                && current_timestamp <= info.end_time + self.reveal_duration(id).get();
+            if in_reveal_window {
+                result.push(id);
+            }
+        }
+        result
+    }
+
+    /// Weight of the ranked ballots preferring A over B and B over A. A ranked candidate is preferred
+    /// over an unranked one; ballots ranking neither count for no side.
+    #[view(getHeadToHead)]
+    fn get_head_to_head(
+        &self,
+        election_id: u64,
+        candidate_a: ManagedBuffer,
+        candidate_b: ManagedBuffer,
+    ) -> MultiValue2<u64, u64> {
+        require!(self.candidates(election_id).contains(&candidate_a), "Invalid candidate");
+        require!(self.candidates(election_id).contains(&candidate_b), "Invalid candidate");
+        require!(candidate_a != candidate_b, "Candidates must differ");
+
+        self.pairwise_counts(election_id, &candidate_a, &candidate_b).into()
+    }
+
+    /// The candidate beating every other one head-to-head, if any; none on a cycle or pairwise tie
+    #[view(getCondorcetWinner)]
+    fn get_condorcet_winner(&self, election_id: u64) -> OptionalValue<ManagedBuffer> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let candidates = self.election_info(election_id).get().candidates;
+        let count = candidates.len();
+        let matrix = self.pairwise_matrix(election_id, &candidates);
+        for (index, candidate) in candidates.iter().enumerate() {
+            let beats_all = (0..count)
+                .all(|other| other == index || matrix.get(index * count + other) > matrix.get(other * count + index));
+            if beats_all {
+                return OptionalValue::Some(candidate.clone_value());
+            }
+        }
+        OptionalValue::None
+    }
+
+    /// Row-major `candidates`² matrix whose cell (a, b) is the weight of the ballots preferring a over b.
+    /// Built in one pass over the ranked ballots, reading each ballot once.
+    fn pairwise_matrix(&self, election_id: u64, candidates: &ManagedVec<ManagedBuffer>) -> ManagedVec<u64> {
+        let count = candidates.len();
+        let mut matrix = ManagedVec::new();
+        for _ in 0..count * count {
+            matrix.push(0u64);
+        }
+
+        for voter in self.ranked_voters(election_id).iter() {
+            let weight = self.voter_cast_weight(election_id, &voter);
+            let mut ranked = [false; MAX_CANDIDATES];
+            for choice in self.ranked_ballot(election_id, &voter).get().iter() {
+                let Some(index) = candidates.iter().position(|c| *c == *choice) else {
+                    continue;
+                };
+                if ranked[index] {
+                    continue;
+                }
+                ranked[index] = true;
+                for other in (0..count).filter(|&other| !ranked[other]) {
+                    let cell = index * count + other;
+                    let Some(total) = matrix.get(cell).checked_add(weight) else {
+                        sc_panic!("Vote count overflow");
+                    };
+                    let _ = matrix.set(cell, total);
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Instant-runoff winner: the candidate with the fewest first preferences among those still standing
+    /// is eliminated until one holds a weighted majority of the ballots that rank a remaining candidate.
+    /// A tie for last place eliminates the later-registered candidate. None without ranked ballots.
+    #[view(computeIrvWinner)]
+    fn compute_irv_winner(&self, election_id: u64) -> OptionalValue<ManagedBuffer> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut remaining = self.election_info(election_id).get().candidates;
+        while !remaining.is_empty() {
+            let mut counts = ManagedVec::<Self::Api, u64>::new();
+            for _ in 0..remaining.len() {
+                counts.push(0u64);
+            }
+
+            let mut active_ballots = 0u64;
+            for voter in self.ranked_voters(election_id).iter() {
+                let ballot = self.ranked_ballot(election_id, &voter).get();
+                let preferred = ballot
+                    .iter()
+                    .find_map(|choice| remaining.iter().position(|candidate| *candidate == *choice));
+                if let Some(index) = preferred {
+                    let weight = self.voter_cast_weight(election_id, &voter);
+                    let _ = counts.set(index, counts.get(index) + weight);
+                    active_ballots += weight;
+                }
+            }
+            if active_ballots == 0 {
+                return OptionalValue::None;
+            }
+
+            let mut lowest = 0;
+            for i in 0..remaining.len() {
+                if counts.get(i) > active_ballots - counts.get(i) {
+                    return OptionalValue::Some(remaining.get(i).clone_value());
+                }
+                if counts.get(i) <= counts.get(lowest) {
+                    lowest = i;
+                }
+            }
+            remaining.remove(lowest);
+        }
+        OptionalValue::None
+    }
+
+    /// Registered candidates by Borda points, highest first (ties keep ballot order). With n candidates
+    /// a ballot gives n-1 points to its first choice, one less to each next one, and 0 to unranked ones,
+    /// each multiplied by the voter's weight.
+    #[view(getBordaResults)]
+    fn get_borda_results(&self, election_id: u64) -> MultiValueEncoded<MultiValue2<ManagedBuffer, u64>> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let mut candidates = self.ordered_candidates(election_id, &info);
+        let mut points = self.borda_points(election_id, &candidates);
+
+        for i in 1..points.len() {
+            let mut j = i;
+            while j > 0 && points.get(j) > points.get(j - 1) {
+                let (current, previous) = (points.get(j), points.get(j - 1));
+                let _ = points.set(j, previous);
+                let _ = points.set(j - 1, current);
+
+                let (candidate, previous) = (candidates.get(j).clone_value(), candidates.get(j - 1).clone_value());
+                let _ = candidates.set(j, previous);
+                let _ = candidates.set(j - 1, candidate);
+                j -= 1;
+            }
+        }
+
+        let mut result = MultiValueEncoded::new();
+        for (i, candidate) in candidates.iter().enumerate() {
+            result.push((candidate.clone_value(), points.get(i)).into());
+        }
+        result
+    }
+
+    /// Borda points aligned with `candidates`; empty when there are no candidates
+    fn borda_points(&self, election_id: u64, candidates: &ManagedVec<ManagedBuffer>) -> ManagedVec<u64> {
+        let top_points = (candidates.len() as u64).saturating_sub(1);
+        let mut points = ManagedVec::new();
+        for _ in 0..candidates.len() {
+            points.push(0u64);
+        }
+
+        for voter in self.ranked_voters(election_id).iter() {
+            let weight = self.voter_cast_weight(election_id, &voter);
+            for (rank, choice) in self.ranked_ballot(election_id, &voter).get().iter().enumerate() {
+                if let Some(index) = candidates.iter().position(|c| *c == *choice) {
+                    let Some(total) = (top_points - rank as u64)
+                        .checked_mul(weight)
+                        .and_then(|earned| earned.checked_add(points.get(index)))
+                    else {
+                        sc_panic!("Vote count overflow");
+                    };
+                    let _ = points.set(index, total);
+                }
+            }
+        }
+        points
+    }
+
+    fn pairwise_counts(&self, election_id: u64, a: &ManagedBuffer, b: &ManagedBuffer) -> (u64, u64) {
+        let mut a_over_b = 0u64;
+        let mut b_over_a = 0u64;
+        for voter in self.ranked_voters(election_id).iter() {
+            let weight = self.voter_cast_weight(election_id, &voter);
+            match self.compare_ranks(&self.ranked_ballot(election_id, &voter).get(), a, b) {
+                core::cmp::Ordering::Less => a_over_b += weight,
+                core::cmp::Ordering::Greater => b_over_a += weight,
+                core::cmp::Ordering::Equal => {},
+            }
+        }
+        (a_over_b, b_over_a)
+    }
+
+    /// Less when the ballot ranks `a` above `b`, Greater for the reverse, Equal when it ranks neither
+    fn compare_ranks(&self, ballot: &ManagedVec<ManagedBuffer>, a: &ManagedBuffer, b: &ManagedBuffer) -> core::cmp::Ordering {
+        for candidate in ballot.iter() {
+            if *candidate == *a {
+                return core::cmp::Ordering::Less;
+            }
+            if *candidate == *b {
+                return core::cmp::Ordering::Greater;
+            }
+        }
+        core::cmp::Ordering::Equal
+    }
+
+    /// False for unknown elections instead of reverting
+    #[view(hasVoted)]
+    fn has_voted_view(&self, election_id: u64, voter: ManagedAddress) -> bool {
+        !self.election_info(election_id).is_empty() && self.has_voted(election_id).contains(&voter)
+    }
+
+    /// List, Merkle-registered or required-token eligibility; false for unknown elections instead of reverting
+    #[view(isEligible)]
+    fn is_eligible(&self, election_id: u64, voter: ManagedAddress) -> bool {
+        !self.election_info(election_id).is_empty() && self.has_voting_access(election_id, &voter)
+    }
+
+    /// `isEligible` for each address, paired with it in input order
+    #[view(checkEligibilityBatch)]
+    fn check_eligibility_batch(
+        &self,
+        election_id: u64,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, bool>> {
+        let exists = !self.election_info(election_id).is_empty();
+        let mut result = MultiValueEncoded::new();
+        for address in addresses {
+            let eligible = exists && self.has_voting_access(election_id, &address);
+            result.push((address, eligible).into());
+        }
+        result
+    }
+
+    /// Candidate the voter currently backs via `vote`/`changeVote`. None if they have not voted,
+    /// abstained, used another ballot type, or voted in an encrypted election
+    #[view(getVoterChoice)]
+    fn get_voter_choice(&self, election_id: u64, voter: ManagedAddress) -> Option<ManagedBuffer> {
+        let choice = self.voter_choice(election_id, &voter);
+        if choice.is_empty() {
+            return None;
+        }
+        Some(choice.get())
+    }
+
+    /// Dry run of `vote`: whether it would succeed for `voter`, and otherwise the error it would raise
+    #[view(canVote)]
+    fn can_vote(&self, election_id: u64, voter: ManagedAddress, candidate: ManagedBuffer) -> MultiValue2<bool, ManagedBuffer> {
+        match self.vote_blocker(election_id, &voter, &candidate) {
+            Some(reason) => (false, ManagedBuffer::from(reason)).into(),
+            None => (true, ManagedBuffer::new()).into(),
+        }
+    }
+
+    /// Mirrors the preconditions of `vote` and `record_ballot`, in the same order
+    fn vote_blocker(&self, election_id: u64, voter: &ManagedAddress, candidate: &ManagedBuffer) -> Option<&'static str> {
+        if self.election_info(election_id).is_empty() {
+            return Some("Election does not exist");
+        }
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        if current_timestamp < info.start_time {
+            return Some("Election not started");
+        }
+        if current_timestamp > info.end_time {
+            return Some("Election ended");
+        }
+        if info.is_finalized {
+            return Some("Election finalized");
+        }
+        if self.paused().get() {
+            return Some("Contract is paused");
+        }
+        if self.election_paused(election_id).get() {
+            return Some("Election paused");
+        }
+        if !self.is_method_allowed(election_id, METHOD_SINGLE_CHOICE) {
+            return Some("Voting method not allowed");
+        }
+        if let Some(reason) = self.voter_mode_blocker(election_id, voter) {
+            return Some(reason);
+        }
+        if self.is_barred_organizer(election_id, voter) {
+            return Some("Organizer cannot vote in this election");
+        }
+        if !self.is_eligible_voter(election_id, voter) {
+            if self.holds_required_token(election_id, voter) {
+                return Some("Token holders vote with voteWithToken");
+            }
+            return Some("Not eligible to vote");
+        }
+        if !self.delegation(election_id, voter).is_empty() {
+            return Some("Vote delegated");
+        }
+
+        let mut previous = None;
+        if self.has_voted(election_id).contains(voter) {
+            if !self.last_vote_wins(election_id).get() {
+                return Some("Already voted");
+            }
+            if self.voter_choice(election_id, voter).is_empty() {
+                return Some("Vote cannot be changed");
+            }
+            previous = Some(self.voter_choice(election_id, voter).get());
+        }
+        if let Err(reason) = self.checked_vote_weight(election_id, voter) {
+            return Some(reason);
+        }
+
+        if info.encryption_public_key.is_some() {
+            return None;
+        }
+        if !self.open_tally(election_id).get() {
+            return Some("Election encryption keys not set");
+        }
+        if !self.candidates(election_id).contains(candidate) {
+            return Some("Invalid candidate");
+        }
+        if self.disqualified(election_id).contains(candidate) {
+            return Some("Candidate disqualified");
+        }
+        // A last-vote-wins voter re-picking their current candidate frees their own slot first
+        let cap_mapper = self.candidate_cap(election_id, candidate);
+        let is_full = !cap_mapper.is_empty() && self.vote_counts(election_id, candidate).get() >= cap_mapper.get();
+        if is_full && previous.as_ref() != Some(candidate) {
+            return Some("Candidate is full");
+        }
+        None
+    }
+
+    /// Every condition currently preventing `endElection` from finalizing, by its error message;
+    /// an empty result means the election can be finalized now.
+    /// Encrypted elections are finalized by `publishResults` and report that instead.
+    #[view(getFinalizationPreconditions)]
+    fn get_finalization_preconditions(&self, election_id: u64) -> MultiValueEncoded<ManagedBuffer> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let reveal_duration = self.reveal_duration(election_id).get();
+
+        let mut blockers = MultiValueEncoded::new();
+        if info.is_finalized {
+            blockers.push(ManagedBuffer::from("Election already finalized"));
+        }
+        if current_timestamp <= info.end_time {
+            blockers.push(ManagedBuffer::from("Election not yet ended"));
+        }
+        if reveal_duration > 0 && current_timestamp <= info.end_time + reveal_duration {
+            blockers.push(ManagedBuffer::from("Reveal window still open"));
+        }
+        if self.total_weighted_votes(election_id).get() < self.min_total_weight(election_id).get() {
+            blockers.push(ManagedBuffer::from("Minimum total weight not reached"));
+        }
+        if !self.open_tally(election_id).get() && !info.is_finalized {
+            blockers.push(ManagedBuffer::from("Awaiting decrypted results"));
+        }
+        blockers
+    }
+
+    /// Dry run of `endElection`: the results it would store together with whatever still blocks it.
+    /// Encrypted elections have no on-chain tally, so only their blockers are reported.
+    #[view(previewFinalize)]
+    fn preview_finalize(&self, election_id: u64) -> FinalizationPreview<Self::Api> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let (candidates, counts) = if self.open_tally(election_id).get() {
+            self.tabulate_results(election_id)
+        } else {
+            (ManagedVec::new(), ManagedVec::new())
+        };
+        FinalizationPreview {
+            candidates,
+            counts,
+            blockers: self.get_finalization_preconditions(election_id).to_vec(),
+        }
+    }
+
+    /// Ended but unfinalized elections with the reason they are still open, scanning at most
+    /// MAX_ELECTION_SCAN of the most recent elections. BLOCKED_READY means only `endElection` is missing.
+    #[view(getEndedUnfinalizedElections)]
+    fn get_ended_unfinalized_elections(&self) -> MultiValueEncoded<MultiValue2<u64, u8>> {
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let mut result = MultiValueEncoded::new();
+
+        let last_id = self.last_election_id().get();
+        let first_id = last_id.saturating_sub(MAX_ELECTION_SCAN) + 1;
+        for id in first_id..=last_id {
+            if self.election_info(id).is_empty() {
+                continue;
+            }
+            let info = self.election_info(id).get();
+            if info.is_finalized || current_timestamp <= info.end_time {
+                continue;
+            }
+
+            result.push((id, self.finalization_block_reason(id, &info, current_timestamp)).into());
+        }
+        result
+    }
+
+    /// What keeps an ended, unfinalized election from being finalized, as a BLOCKED_* code
+    fn finalization_block_reason(&self, election_id: u64, info: &ElectionInfo<Self::Api>, current_timestamp: u64) -> u8 {
+        if current_timestamp <= info.end_time + self.reveal_duration(election_id).get() {
+            BLOCKED_AWAITING_REVEAL
+        } else if self.total_weighted_votes(election_id).get() < self.min_total_weight(election_id).get() {
+            BLOCKED_BELOW_MIN_WEIGHT
+        } else if !self.open_tally(election_id).get() {
+            BLOCKED_AWAITING_DECRYPTION
+        } else {
+            BLOCKED_READY
+        }
+    }
+
+    /// Elections created by `organizer`, automatic revotes and clones included
+    #[view(getElectionCountByOrganizer)]
+    fn get_election_count_by_organizer(&self, organizer: ManagedAddress) -> u64 {
+        self.elections_created(&organizer).get()
+    }
+
+    #[view(getCreators)]
+    fn get_creators(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.creators().iter().collect()
+    }
+
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool {
+        self.paused().get()
+    }
+
+    #[view(isCancelled)]
+    fn is_cancelled(&self, election_id: u64) -> bool {
+        self.cancelled_elections().contains(&election_id)
+    }
+
+    #[view(getOrganizer)]
+    fn get_organizer(&self) -> ManagedAddress {
+        self.organizer().get()
+    }
+
+    #[view(getPendingOrganizer)]
+    fn get_pending_organizer(&self) -> OptionalValue<ManagedAddress> {
+        if self.pending_organizer().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.pending_organizer().get())
+        }
+    }
+
+    #[view(isOrganizerRenounced)]
+    fn is_organizer_renounced(&self) -> bool {
+        self.organizer_renounced().get()
+    }
+
+    #[view(isOrganizer)]
+    fn is_organizer(&self) -> bool {
+        let caller = self.blockchain().get_caller();
+        caller == self.organizer().get()
+    }
+
+    /// Address that created the election; unlike the organizer it never changes.
+    /// None for elections created before creators were recorded.
+    #[view(getElectionCreator)]
+    fn get_election_creator(&self, election_id: u64) -> OptionalValue<ManagedAddress> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        if self.election_creator(election_id).is_empty() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(self.election_creator(election_id).get())
+    }
+
+    /// Informational ownership record: admission to organizer endpoints is decided by `require_organizer`,
+    /// never by this address. Elections created before it was tracked belong to the global organizer.
+    #[view(getElectionOrganizer)]
+    fn get_election_organizer(&self, election_id: u64) -> ManagedAddress {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        if self.election_organizer(election_id).is_empty() {
+            return self.organizer().get();
+        }
+        self.election_organizer(election_id).get()
+    }
+
+    /// Maintenance view: returns whatever is stored for an id without decoding or defaulting
+    #[view(getElectionRaw)]
+    fn get_election_raw(&self, election_id: u64) -> ElectionRaw<Self::Api> {
+        ElectionRaw {
+            info_bytes: self.election_info_raw(election_id).get(),
+            candidates_empty: self.candidates(election_id).is_empty(),
+            eligible_voters_empty: self.eligible_voters(election_id).is_empty(),
+            has_voted_empty: self.has_voted(election_id).is_empty(),
+            encrypted_votes_empty: self.encrypted_votes(election_id).is_empty(),
+            final_candidates_empty: self.final_candidates(election_id).is_empty(),
+            final_counts_empty: self.final_counts(election_id).is_empty(),
+        }
+    }
+
+    /// Returns 0 when no automatic revote was opened
+    #[view(getRevoteElection)]
+    fn get_revote_election(&self, election_id: u64) -> u64 {
+        self.revote_election(election_id).get()
+    }
+
+    /// Returns the current block timestamp and nonce so clients can anchor their clocks to the chain
+    #[view(getBlockTimeContext)]
+    fn get_block_time_context(&self) -> MultiValue2<u64, u64> {
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        let block_nonce = self.blockchain().get_block_nonce();
+        (current_timestamp, block_nonce).into()
+    }
+
+    /// Returns (current timestamp, seconds until start, seconds until end), the last two 0 once passed,
+    /// so countdowns follow the chain's clock instead of the client's
+    #[view(getTimeStatus)]
+    fn get_time_status(&self, election_id: u64) -> MultiValue3<u64, u64, u64> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
+        (
+            current_timestamp,
+            info.start_time.saturating_sub(current_timestamp),
+            info.end_time.saturating_sub(current_timestamp),
+        )
+            .into()
+    }
+
+    /// Compile-time `MAX_CANDIDATES`, for validating candidate lists before submitting
+    #[view(maxCandidates)]
+    fn max_candidates(&self) -> usize {
+        MAX_CANDIDATES
+    }
+
+    /// Results for display: candidates below the election's display threshold are omitted,
+    /// their votes still counting towards the totals
+    #[view(getElectionResults)]
+    fn get_election_results(&self, election_id: u64) -> MultiValueEncoded<(ManagedBuffer, u64)> {
+        let threshold = self.display_threshold(election_id).get();
+        let mut result = MultiValueEncoded::new();
+        for (candidate, count) in self.get_election_results_full(election_id) {
+            if count >= threshold {
+                result.push((candidate, count));
+            }
+        }
+        result
+    }
+
+    /// `getElectionResults` for several elections in one call; unknown ids are skipped.
+    /// Each entry is a struct because nested multi-values could not be split apart again.
+    #[view(getResultsBatch)]
+    fn get_results_batch(&self, election_ids: MultiValueEncoded<u64>) -> MultiValueEncoded<ElectionResults<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for election_id in election_ids {
+            if self.election_info(election_id).is_empty() {
+                continue;
+            }
+
+            let mut candidates = ManagedVec::new();
+            let mut counts = ManagedVec::new();
+            for (candidate, count) in self.get_election_results(election_id) {
+                candidates.push(candidate);
+                counts.push(count);
+            }
+            result.push(ElectionResults { election_id, candidates, counts });
+        }
+        result
+    }
+
+    /// One candidate's count: the recorded final count once finalized, the live count before
+    #[view(getCandidateVotes)]
+    fn get_candidate_votes(&self, election_id: u64, candidate: ManagedBuffer) -> u64 {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        if self.election_info(election_id).get().is_finalized {
+            let index = self.final_candidates(election_id).get().iter().position(|c| *c == candidate);
+            let Some(index) = index else {
+                sc_panic!("Unknown candidate");
+            };
+            return self.final_counts(election_id).get().get(index);
+        }
+
+        require!(self.candidates(election_id).contains(&candidate), "Unknown candidate");
+        self.vote_counts(election_id, &candidate).get()
+    }
+
+    #[view(getElectionResultsFull)]
+    fn get_election_results_full(&self, election_id: u64) -> MultiValueEncoded<(ManagedBuffer, u64)> {
+        let info = self.election_info(election_id).get();
+        
+        // Only allow viewing results after election is finalized
+        // This ensures votes remain private during and immediately after election
+        if info.is_finalized {
+             let candidates = self.final_candidates(election_id).get();
+             let counts = self.final_counts(election_id).get();
+             
+             let mut output = MultiValueEncoded::new();
+             let len = candidates.len();
+             for i in 0..len {
+                 let candidate = candidates.get(i);
+                 let count = counts.get(i);
+                 output.push((candidate.clone_value(), count));
+             }
+             return output;
+        }
+
+        // Open-tally elections expose their live counts
+        let mut result = MultiValueEncoded::new();
+        if self.open_tally(election_id).get() {
+            for candidate in self.tally_candidates(election_id, &info).iter() {
+                let count = self.vote_counts(election_id, &candidate).get();
+                result.push((candidate.clone_value(), count));
+            }
+            return result;
+        }
+
+        // Before finalization, return empty results (votes are private)
+        for candidate in self.candidates(election_id).iter() {
+            result.push((candidate, 0));
+        }
+        result
+    }
+
+    /// Top candidate and its count, from the final results once finalized, else from the live tally.
+    /// On a tie the first tied candidate in result order is returned; `getWinners` lists all of them.
+    #[view(getWinner)]
+    fn get_winner(&self, election_id: u64) -> MultiValue2<ManagedBuffer, u64> {
+        let (candidates, counts) = self.standing_results(election_id);
+        require!(!candidates.is_empty(), "No candidates");
+
+        let mut winner = 0;
+        for i in 1..counts.len() {
+            if counts.get(i) > counts.get(winner) {
+                winner = i;
+            }
+        }
+        (candidates.get(winner).clone_value(), counts.get(winner)).into()
+    }
+
+    /// Every candidate at the top count, in result order; with no votes yet that is every candidate
+    #[view(getWinners)]
+    fn get_winners(&self, election_id: u64) -> MultiValueEncoded<ManagedBuffer> {
+        let (candidates, counts) = self.standing_results(election_id);
+        let top = counts.iter().max().unwrap_or(0);
+
+        let mut result = MultiValueEncoded::new();
+        for (i, candidate) in candidates.iter().enumerate() {
+            if counts.get(i) == top {
+                result.push(candidate.clone_value());
+            }
+        }
+        result
+    }
+
+    fn standing_results(&self, election_id: u64) -> (ManagedVec<ManagedBuffer>, ManagedVec<u64>) {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let info = self.election_info(election_id).get();
+        if info.is_finalized {
+            return (self.final_candidates(election_id).get(), self.final_counts(election_id).get());
+        }
+        self.tabulate_results(election_id)
+    }
+
+    /// Single top candidate of a finalized election; a tie at the top (or no votes) has no winner
+    fn final_winner(&self, election_id: u64) -> Option<(ManagedBuffer, u64)> {
+        let candidates = self.final_candidates(election_id).get();
+        let counts = self.final_counts(election_id).get();
+
+        let mut winner: Option<(ManagedBuffer, u64)> = None;
+        let mut tied = false;
+        for (i, candidate) in candidates.iter().enumerate() {
+            let count = counts.get(i);
+            match &winner {
+                Some((_, top)) if count < *top => {}
+                Some((_, top)) if count == *top => tied = true,
+                _ => {
+                    winner = Some((candidate.clone_value(), count));
+                    tied = false;
+                }
+            }
+        }
+
+        match winner {
+            Some((_, 0)) => None,
+            _ if tied => None,
+            winner => winner,
+        }
+    }
+
+    /// (election_id, winner, count) for each finalized election of the group; elections still
+    /// running are skipped, and ties are reported with an empty winner and the top count
+    #[view(getGroupWinners)]
+    fn get_group_winners(&self, group_id: u64) -> MultiValueEncoded<MultiValue3<u64, ManagedBuffer, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for election_id in self.group_elections(group_id).iter() {
+            if !self.election_info(election_id).get().is_finalized {
+                continue;
+            }
+            let entry = match self.final_winner(election_id) {
+                Some((winner, count)) => (election_id, winner, count),
+                None => {
+                    let top = self.final_counts(election_id).get().iter().max().unwrap_or_default();
+                    (election_id, ManagedBuffer::new(), top)
+                }
+            };
+            result.push(entry.into());
+        }
+        result
+    }
+
+    /// Results of `getElectionResults` as "name,count\n" lines for CSV export, without a header.
+    /// Names containing commas, quotes or line breaks are quoted as in RFC 4180.
+    #[view(getElectionResultsCsv)]
+    fn get_election_results_csv(&self, election_id: u64) -> ManagedBuffer {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut csv = ManagedBuffer::new();
+        for (candidate, count) in self.get_election_results(election_id) {
+            self.append_csv_field(&mut csv, &candidate);
+            csv.append_bytes(b",");
+            self.append_decimal(&mut csv, count);
+            csv.append_bytes(b"\n");
+        }
+        csv
+    }
+
+    fn append_csv_field(&self, csv: &mut ManagedBuffer, field: &ManagedBuffer) {
+        let needs_quotes = |bytes: &[u8]| bytes.iter().any(|b| matches!(b, b',' | b'"' | b'\n' | b'\r'));
+
+        let mut chunk = [0u8; 32];
+        let mut quoted = false;
+        let mut offset = 0;
+        while offset < field.len() && !quoted {
+            let size = (field.len() - offset).min(32);
+            field.load_slice(offset, &mut chunk[..size]);
+            quoted = needs_quotes(&chunk[..size]);
+            offset += size;
+        }
+        if !quoted {
+            csv.append(field);
+            return;
+        }
+
+        csv.append_bytes(b"\"");
+        offset = 0;
+        while offset < field.len() {
+            let size = (field.len() - offset).min(32);
+            field.load_slice(offset, &mut chunk[..size]);
+            for byte in &chunk[..size] {
+                if *byte == b'"' {
+                    csv.append_bytes(b"\"\"");
+                } else {
+                    csv.append_bytes(&[*byte]);
+                }
+            }
+            offset += size;
+        }
+        csv.append_bytes(b"\"");
+    }
+
+    fn append_decimal(&self, buffer: &mut ManagedBuffer, mut value: u64) {
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        buffer.append_bytes(&digits[start..]);
+    }
+
+    /// keccak256 over the finalized (candidate, count) pairs, nested-encoded in result order
+    #[view(getResultsHash)]
+    fn get_results_hash(&self, election_id: u64) -> ManagedBuffer {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.election_info(election_id).get().is_finalized, "Election not finalized");
+
+        let mut preimage = ManagedBuffer::new();
+        preimage.append_bytes(&election_id.to_be_bytes());
+        let counts = self.final_counts(election_id).get();
+        for (i, candidate) in self.final_candidates(election_id).get().iter().enumerate() {
+            preimage.append_u32_be(candidate.len() as u32);
+            preimage.append(&candidate);
+            preimage.append_bytes(&counts.get(i).to_be_bytes());
+        }
+        self.hash_leaf(&preimage)
+    }
+
+    /// Proof package for a finalized election: results with their hash, the vote receipt root,
+    /// turnout and the finalization facts, in one read
+    #[view(getAuditBundle)]
+    fn get_audit_bundle(&self, election_id: u64) -> AuditBundle<Self::Api> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        let info = self.election_info(election_id).get();
+        require!(info.is_finalized, "Election not finalized");
+
+        let votes_root = if self.votes_tree_size(election_id).get() == 0 {
+            None
+        } else {
+            Some(self.get_votes_root(election_id))
+        };
+        AuditBundle {
+            info,
+            candidates: self.final_candidates(election_id).get(),
+            counts: self.final_counts(election_id).get(),
+            results_hash: self.get_results_hash(election_id),
+            votes_root,
+            ballots_cast: self.ballots_cast(election_id),
+            eligible_at_finalization: self.final_eligible_count(election_id).get(),
+            min_weight_reached: self.total_weighted_votes(election_id).get() >= self.min_total_weight(election_id).get(),
+            finalized_at: self.finalized_at(election_id).get(),
+        }
+    }
+
+    /// Root over every vote receipt cast so far, empty leaves being 32 zero bytes
+    #[view(getVotesRoot)]
+    fn get_votes_root(&self, election_id: u64) -> ManagedBuffer {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut zero = ManagedBuffer::new_from_bytes(&[0u8; 32]);
+        let mut node = zero.clone();
+        let mut size = self.votes_tree_size(election_id).get();
+        for level in 0..VOTES_TREE_DEPTH {
+            if size & 1 == 1 {
+                node = self.hash_sorted_pair(&self.votes_tree_branch(election_id, level).get(), &node);
+            } else {
+                node = self.hash_sorted_pair(&node, &zero);
+            }
+            zero = self.hash_sorted_pair(&zero, &zero);
+            size >>= 1;
+        }
+        node
+    }
+
+    /// The proof holds one sibling per level (`VOTES_TREE_DEPTH` in total), empty subtrees included
+    #[view(verifyVoteInclusion)]
+    fn verify_vote_inclusion(
+        &self,
+        election_id: u64,
+        receipt: ManagedBuffer,
+        proof: MultiValueEncoded<ManagedBuffer>,
+    ) -> bool {
+        if proof.len() != VOTES_TREE_DEPTH as usize || receipt == ManagedBuffer::new_from_bytes(&[0u8; 32]) {
+            return false;
+        }
+
+        let mut node = receipt;
+        for sibling in proof {
+            node = self.hash_sorted_pair(&node, &sibling);
+        }
+        node == self.get_votes_root(election_id)
+    }
+
+    /// Eligible voters (list plus Merkle-registered) when the election was finalized
+    #[view(getVoterCountAtFinalization)]
+    fn get_voter_count_at_finalization(&self, election_id: u64) -> u64 {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(self.election_info(election_id).get().is_finalized, "Election not finalized");
+        self.final_eligible_count(election_id).get()
+    }
+
+    /// Returns (voted, eligible) from the address-based voter sets, during and after the election
+    #[view(getTurnout)]
+    fn get_turnout(&self, election_id: u64) -> MultiValue2<u64, u64> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        (self.has_voted(election_id).len() as u64, self.eligible_voters(election_id).len() as u64).into()
+    }
+
+    /// Listed voters who have not voted yet, in the order they were added (e.g. for reminders)
+    #[view(getPendingVoters)]
+    fn get_pending_voters(&self, election_id: u64) -> MultiValueEncoded<ManagedAddress> {
+        self.get_pending_voters_paged(election_id, 0, u64::MAX)
+    }
+
+    /// `getPendingVoters` one page at a time: `skip` and `limit` count pending voters only
+    #[view(getPendingVotersPaged)]
+    fn get_pending_voters_paged(&self, election_id: u64, skip: u64, limit: u64) -> MultiValueEncoded<ManagedAddress> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        let mut result = MultiValueEncoded::new();
+        let mut skipped = 0u64;
+        let mut returned = 0u64;
+        for voter in self.eligible_voters(election_id).iter() {
+            if returned == limit {
+                break;
+            }
+            if self.has_voted(election_id).contains(&voter) {
+                continue;
+            }
+            if skipped < skip {
+                skipped += 1;
+                continue;
+            }
+            result.push(voter);
+            returned += 1;
+        }
+        result
+    }
+
+    /// Abstentions recorded through `abstain`; they stay stored after finalization
+    #[view(getAbstentions)]
+    fn get_abstentions(&self, election_id: u64) -> u64 {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        self.abstain_count(election_id).get()
     }
 
-    #[view(verifyMerkleProof)]
-    fn verify_merkle_proof(
-        &self,
-        voter: &ManagedAddress,
-        merkle_root: &ManagedBuffer,
-        proof: &MultiValueEncoded<ManagedBuffer>,
-    ) -> bool {
-        let leaf = self.hash_address(voter);
-        self.verify_merkle_proof_leaf(&leaf, merkle_root, proof)
+    /// How many addresses can vote: the eligibility list for list-mode elections. Merkle elections admit
+    /// an open-ended set of provers, so they return the organizer-declared count, or None when unset.
+    /// Token-gated elections admit any holder and always return None.
+    #[view(getEligibilityCount)]
+    fn get_eligibility_count(&self, election_id: u64) -> OptionalValue<u64> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        if !self.required_token(election_id).is_empty() {
+            OptionalValue::None
+        } else if self.election_info(election_id).get().merkle_root.is_none() {
+            OptionalValue::Some(self.eligible_voters(election_id).len() as u64)
+        } else if self.eligible_count_override(election_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.eligible_count_override(election_id).get())
+        }
     }
 
-    fn hash_address(&self, addr: &ManagedAddress) -> ManagedBuffer {
-        let hash = self.crypto().keccak256(addr.as_managed_buffer());
-        ManagedBuffer::new_from_bytes(&hash.to_byte_array())
+    /// Whether turnout reached the quorum: as recorded at finalization, or live before that
+    #[view(isQuorumMet)]
+    fn is_quorum_met(&self, election_id: u64) -> bool {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
+        if self.election_info(election_id).get().is_finalized {
+            return self.quorum_met(election_id).get();
+        }
+        self.ballots_cast(election_id) >= self.quorum(election_id).get()
     }
 
-    fn verify_merkle_proof_leaf(
-        &self,
-        leaf: &ManagedBuffer,
-        merkle_root: &ManagedBuffer,
-        proof: &MultiValueEncoded<ManagedBuffer>,
-    ) -> bool {
-        let mut current_buffer = leaf.clone();
+    /// Voters marked as having voted plus nullifier ballots, which record no voter address
+    fn ballots_cast(&self, election_id: u64) -> u64 {
+        (self.has_voted(election_id).len() + self.used_nullifiers(election_id).len()) as u64
+    }
 
-        for proof_element in proof.clone() {
-            let mut combined = ManagedBuffer::new();
-            combined.append(&current_buffer);
-            combined.append(&proof_element);
+    #[view(isResultsNotarized)]
+    fn is_results_notarized(&self, election_id: u64) -> bool {
+        self.results_notarized(election_id).get()
+    }
 
-            let hash_result = self.crypto().keccak256(combined);
-            current_buffer = ManagedBuffer::new_from_bytes(&hash_result.to_byte_array());
+    /// Returns pending write-ins with their shadow vote counts
+    #[view(getPendingWriteIns)]
+    fn get_pending_write_ins(&self, election_id: u64) -> MultiValueEncoded<MultiValue2<ManagedBuffer, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for name in self.pending_write_ins(election_id).iter() {
+            let votes = self.write_in_votes(election_id, &name).get();
+            result.push((name, votes).into());
         }
+        result
+    }
 
-        &current_buffer == merkle_root
+    /// Same as `getElectionResults`, with a flag marking disqualified candidates
+    #[view(getElectionResultsWithStatus)]
+    fn get_election_results_with_status(
+        &self,
+        election_id: u64,
+    ) -> MultiValueEncoded<MultiValue3<ManagedBuffer, u64, bool>> {
+        let mut result = MultiValueEncoded::new();
+        for (candidate, count) in self.get_election_results(election_id) {
+            let is_disqualified = self.disqualified(election_id).contains(&candidate);
+            result.push((candidate, count, is_disqualified).into());
+        }
+        result
     }
 
-    #[view(getAllElections)]
-    fn get_all_elections(&self) -> MultiValueEncoded<ElectionInfo<Self::Api>> {
+    /// Returns per-candidate vote differences between two snapshots (1-based indices, b minus a).
+    /// Out-of-range indices yield an empty result.
+    #[view(getResultsDelta)]
+    fn get_results_delta(
+        &self,
+        election_id: u64,
+        snapshot_index_a: usize,
+        snapshot_index_b: usize,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, i64>> {
         let mut result = MultiValueEncoded::new();
-        let last_id = self.last_election_id().get();
-        for id in 1..=last_id {
-            let info = self.election_info(id).get();
-            result.push(info);
+        if self.election_info(election_id).is_empty() {
+            return result;
+        }
+
+        let snapshots = self.result_snapshots(election_id);
+        let len = snapshots.len();
+        if snapshot_index_a == 0 || snapshot_index_b == 0 || snapshot_index_a > len || snapshot_index_b > len {
+            return result;
+        }
+
+        let snapshot_a = snapshots.get(snapshot_index_a);
+        let snapshot_b = snapshots.get(snapshot_index_b);
+        let info = self.election_info(election_id).get();
+        for candidate in self.tally_candidates(election_id, &info).iter() {
+            let count_a = self.snapshot_count(&snapshot_a, &candidate);
+            let count_b = self.snapshot_count(&snapshot_b, &candidate);
+            result.push((candidate.clone_value(), count_b as i64 - count_a as i64).into());
         }
         result
     }
 
-    #[view(isOrganizer)]
-    fn is_organizer(&self) -> bool {
-        let caller = self.blockchain().get_caller();
-        caller == self.organizer().get()
+    /// Candidates that joined after the snapshot was taken count as zero in it
+    fn snapshot_count(&self, snapshot: &ResultSnapshot<Self::Api>, candidate: &ManagedBuffer) -> u64 {
+        match snapshot.candidates.iter().position(|c| *c == *candidate) {
+            Some(index) => snapshot.counts.get(index),
+            None => 0,
+        }
     }
 
-    #[view(getElectionResults)]
-    fn get_election_results(&self, election_id: u64) -> MultiValueEncoded<(ManagedBuffer, u64)> {
+    /// (snapshot_timestamp, count) series of one candidate, for sparklines
+    #[view(getCandidateTrend)]
+    fn get_candidate_trend(&self, election_id: u64, candidate: ManagedBuffer) -> MultiValueEncoded<MultiValue2<u64, u64>> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+
         let info = self.election_info(election_id).get();
-        
-        // Only allow viewing results after election is finalized
-        // This ensures votes remain private during and immediately after election
-        if info.is_finalized {
-             let candidates = self.final_candidates(election_id).get();
-             let counts = self.final_counts(election_id).get();
-             
-             let mut output = MultiValueEncoded::new();
-             let len = candidates.len();
-             for i in 0..len {
-                 let candidate = candidates.get(i);
-                 let count = counts.get(i);
-                 output.push((candidate.clone_value(), count));
-             }
-             return output;
+        let is_tallied = self.tally_candidates(election_id, &info).iter().any(|c| *c == candidate);
+        require!(is_tallied, "Invalid candidate");
+
+        let mut result = MultiValueEncoded::new();
+        for snapshot in self.result_snapshots(election_id).iter() {
+            result.push((snapshot.timestamp, self.snapshot_count(&snapshot, &candidate)).into());
         }
+        result
+    }
+
+    /// Returns (bucket_start, cumulative_votes) pairs built from the stored result snapshots,
+    /// keeping the latest snapshot of each bucket. Bounded by the number of snapshots rather than voters.
+    #[view(getTurnoutOverTime)]
+    fn get_turnout_over_time(
+        &self,
+        election_id: u64,
+        bucket_seconds: u64,
+    ) -> MultiValueEncoded<MultiValue2<u64, u64>> {
+        require!(!self.election_info(election_id).is_empty(), "Election does not exist");
+        require!(bucket_seconds > 0, "Bucket size must be positive");
 
-        // Before finalization, return empty results (votes are private)
         let mut result = MultiValueEncoded::new();
-        for candidate in self.candidates(election_id).iter() {
-            result.push((candidate, 0));
+        let mut current_bucket: Option<(u64, u64)> = None;
+        for snapshot in self.result_snapshots(election_id).iter() {
+            let bucket_start = snapshot.timestamp - snapshot.timestamp % bucket_seconds;
+            let cumulative_votes = snapshot.counts.iter().sum();
+
+            if let Some((start, votes)) = current_bucket {
+                if start != bucket_start {
+                    result.push((start, votes).into());
+                }
+            }
+            current_bucket = Some((bucket_start, cumulative_votes));
+        }
+
+        if let Some((start, votes)) = current_bucket {
+            result.push((start, votes).into());
         }
         result
     }
@@ -343,10 +3693,98 @@ pub trait VotingApp {
             return output;
         }
 
-        // Otherwise, return the live candidates set
+        // Otherwise, return the live candidates in ballot order
         let mut result = MultiValueEncoded::new();
-        for candidate in self.candidates(election_id).iter() {
-            result.push(candidate);
+        for candidate in self.ordered_candidates(election_id, &info).iter() {
+            result.push(candidate.clone_value());
+        }
+        result
+    }
+
+    fn ordered_candidates(&self, election_id: u64, info: &ElectionInfo<Self::Api>) -> ManagedVec<ManagedBuffer> {
+        let mut candidates = info.candidates.clone();
+        let mut keys = match self.ballot_order_mode(election_id).get() {
+            BallotOrderMode::Insertion => return candidates,
+            BallotOrderMode::Alphabetical => candidates.clone(),
+            BallotOrderMode::Randomized => {
+                let seed = self.ballot_seed(election_id).get();
+                let mut keys = ManagedVec::new();
+                for candidate in candidates.iter() {
+                    let mut preimage = seed.clone();
+                    preimage.append(&candidate);
+                    keys.push(self.hash_leaf(&preimage));
+                }
+                keys
+            },
+        };
+
+        // Insertion sort by key; ballots are small
+        for i in 1..keys.len() {
+            let mut j = i;
+            while j > 0 && self.compare_buffers(&keys.get(j), &keys.get(j - 1)) == core::cmp::Ordering::Less {
+                let (key, previous_key) = (keys.get(j).clone_value(), keys.get(j - 1).clone_value());
+                let _ = keys.set(j, previous_key);
+                let _ = keys.set(j - 1, key);
+
+                let (candidate, previous) = (candidates.get(j).clone_value(), candidates.get(j - 1).clone_value());
+                let _ = candidates.set(j, previous);
+                let _ = candidates.set(j - 1, candidate);
+                j -= 1;
+            }
+        }
+        candidates
+    }
+
+    /// Lexicographic byte comparison without allocating
+    fn compare_buffers(&self, a: &ManagedBuffer, b: &ManagedBuffer) -> core::cmp::Ordering {
+        let len = a.len().min(b.len());
+        let mut chunk_a = [0u8; 32];
+        let mut chunk_b = [0u8; 32];
+        let mut offset = 0;
+        while offset < len {
+            let size = (len - offset).min(32);
+            a.load_slice(offset, &mut chunk_a[..size]);
+            b.load_slice(offset, &mut chunk_b[..size]);
+            let ordering = chunk_a[..size].cmp(&chunk_b[..size]);
+            if ordering != core::cmp::Ordering::Equal {
+                return ordering;
+            }
+            offset += size;
+        }
+        a.len().cmp(&b.len())
+    }
+
+    /// Diagnostic for "Not eligible" reports: which eligibility path (if any) admits the voter
+    #[view(getVoterEligibilitySource)]
+    fn get_voter_eligibility_source(&self, election_id: u64, voter: ManagedAddress) -> u8 {
+        if self.eligible_voters(election_id).contains(&voter) {
+            return ELIGIBILITY_LIST;
+        }
+        if self.merkle_registered(election_id).contains(&voter) {
+            return ELIGIBILITY_MERKLE;
+        }
+        if self.holds_required_token(election_id, &voter) {
+            return ELIGIBILITY_TOKEN;
+        }
+        ELIGIBILITY_NONE
+    }
+
+    /// Hybrid audit: walks `limit` list voters starting at position `skip` (insertion order) and returns
+    /// those who have not yet registered through a Merkle proof. Proof-registered voters that are missing
+    /// from the list are not reported here since the list is the organizer's reference electorate.
+    #[view(getEligibleButNotRegistered)]
+    fn get_eligible_but_not_registered(
+        &self,
+        election_id: u64,
+        skip: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        let registered = self.merkle_registered(election_id);
+        for voter in self.eligible_voters(election_id).iter().skip(skip).take(limit) {
+            if !registered.contains(&voter) {
+                result.push(voter);
+            }
         }
         result
     }
@@ -360,6 +3798,11 @@ pub trait VotingApp {
         info.encryption_public_key
     }
 
+    #[view(isOpenTally)]
+    fn is_open_tally(&self, election_id: u64) -> bool {
+        self.open_tally(election_id).get()
+    }
+
     /// Returns all encrypted votes for an election (for threshold decryption)
     #[view(getEncryptedVotes)]
     fn get_encrypted_votes(&self, election_id: u64) -> MultiValueEncoded<ManagedBuffer> {
@@ -386,8 +3829,9 @@ pub trait VotingApp {
         let mut info = self.election_info(election_id).get();
         require!(!info.is_finalized, "Results already published");
         
-        let current_timestamp = self.blockchain().get_block_timestamp();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds().as_u64_seconds();
         require!(current_timestamp > info.end_time, "Election still ongoing");
+        self.require_min_total_weight(election_id);
 
         // Store the results
         let mut candidates_vec = ManagedVec::new();
@@ -399,28 +3843,207 @@ pub trait VotingApp {
             counts_vec.push(count);
         }
         
-        self.final_candidates(election_id).set(candidates_vec);
-        self.final_counts(election_id).set(counts_vec);
-        
-        // Mark as finalized
+        self.do_finalize(election_id, &mut info, &candidates_vec, &counts_vec);
+    }
+
+    /// Final candidates and counts of an open-tally election: live counts in tally order, or Borda points
+    /// when the election tallies by Borda count. The one tabulation behind `endElection`, `endElectionBatch`
+    /// and the finalization previews, so they cannot drift apart.
+    fn tabulate_results(&self, election_id: u64) -> (ManagedVec<ManagedBuffer>, ManagedVec<u64>) {
+        let info = self.election_info(election_id).get();
+        let mut candidates_vec = ManagedVec::new();
+        let mut counts_vec = ManagedVec::new();
+        let borda_points = match self.tally_method(election_id).get() {
+            TallyMethod::Plurality => None,
+            TallyMethod::Borda => Some(self.borda_points(election_id, &info.candidates)),
+        };
+        for candidate in self.tally_candidates(election_id, &info).iter() {
+            let count = match &borda_points {
+                None => self.vote_counts(election_id, &candidate).get(),
+                Some(points) => match info.candidates.iter().position(|c| *c == *candidate) {
+                    Some(index) => points.get(index),
+                    None => 0,
+                },
+            };
+            counts_vec.push(count);
+            candidates_vec.push(candidate.clone_value());
+        }
+        (candidates_vec, counts_vec)
+    }
+
+    /// Stores the final results and marks the election finalized. The eligible voter count is
+    /// snapshotted too, so turnout stays computable once eligibility storage is cleared.
+    fn do_finalize(
+        &self,
+        election_id: u64,
+        info: &mut ElectionInfo<Self::Api>,
+        candidates: &ManagedVec<ManagedBuffer>,
+        counts: &ManagedVec<u64>,
+    ) {
+        self.final_candidates(election_id).set(candidates);
+        self.final_counts(election_id).set(counts);
+        let eligible_count = self.eligible_voters(election_id).len() + self.merkle_registered(election_id).len();
+        self.final_eligible_count(election_id).set(eligible_count as u64);
+        self.finalized_at(election_id).set(self.blockchain().get_block_timestamp_seconds().as_u64_seconds());
+        self.quorum_met(election_id).set(self.ballots_cast(election_id) >= self.quorum(election_id).get());
+
         info.is_finalized = true;
-        self.election_info(election_id).set(info);
+        self.election_info(election_id).set(&*info);
+
+        self.election_finalized_event(election_id);
+    }
+
+    /// Elections without an allowed-methods mask accept every method their configuration supports
+    fn is_method_allowed(&self, election_id: u64, method: u8) -> bool {
+        let allowed = self.allowed_methods(election_id).get();
+        allowed == 0 || allowed & method != 0
+    }
+
+    fn require_method_allowed(&self, election_id: u64, method: u8) {
+        require!(self.is_method_allowed(election_id, method), "Voting method not allowed");
+    }
+
+    /// Plain ballot endpoints serve DirectVoting elections; the other modes have their own endpoints
+    fn voting_mode_blocker(&self, election_id: u64) -> Option<&'static str> {
+        match self.get_voting_mode(election_id) {
+            VotingMode::DirectVoting => None,
+            VotingMode::MerkleProof => Some("Election uses Merkle proofs"),
+            VotingMode::CommitReveal => Some("Election uses commit-reveal"),
+            VotingMode::RankedChoice => Some("Election uses ranked ballots"),
+        }
+    }
+
+    /// `voting_mode_blocker` for a known voter: the listed or proof-registered voters of a Merkle election
+    /// (hybrid eligibility) cast plain ballots too
+    fn voter_mode_blocker(&self, election_id: u64, voter: &ManagedAddress) -> Option<&'static str> {
+        if self.get_voting_mode(election_id) == VotingMode::MerkleProof && self.is_eligible_voter(election_id, voter) {
+            return None;
+        }
+        self.voting_mode_blocker(election_id)
+    }
+
+    fn require_direct_voting(&self, election_id: u64, voter: &ManagedAddress) {
+        if let Some(reason) = self.voter_mode_blocker(election_id, voter) {
+            sc_panic!(reason);
+        }
+    }
+
+    /// Moves a direct election into `mode`, or back to direct voting; Merkle elections keep their mode
+    fn switch_voting_mode(&self, election_id: u64, mode: VotingMode, enabled: bool) {
+        let current = self.get_voting_mode(election_id);
+        require!(current == VotingMode::DirectVoting || current == mode, "Election uses another voting mode");
+        self.voting_mode(election_id).set(if enabled { mode } else { VotingMode::DirectVoting });
+    }
+
+    /// Set by `setRestrictOrganizer`; checked by every entry point that casts a ballot for an address
+    fn is_barred_organizer(&self, election_id: u64, voter: &ManagedAddress) -> bool {
+        self.restrict_organizer(election_id).get() && *voter == self.organizer().get()
+    }
+
+    fn require_not_barred_organizer(&self, election_id: u64, voter: &ManagedAddress) {
+        require!(!self.is_barred_organizer(election_id, voter), "Organizer cannot vote in this election");
+    }
+
+    /// Names made only of ASCII whitespace count as empty
+    fn require_valid_candidate_name(&self, name: &ManagedBuffer) {
+        require!(name.len() <= MAX_CANDIDATE_NAME_LEN, "Candidate name too long");
+        let mut buffer = [0u8; MAX_CANDIDATE_NAME_LEN];
+        let bytes = name.load_to_byte_array(&mut buffer);
+        require!(
+            !bytes.iter().all(|byte| byte.is_ascii_whitespace()),
+            "Candidate name cannot be empty",
+        );
+    }
+
+    fn require_min_total_weight(&self, election_id: u64) {
+        require!(
+            self.total_weighted_votes(election_id).get() >= self.min_total_weight(election_id).get(),
+            "Minimum total weight not reached",
+        );
     }
 
+    /// The organizer or any admin
     fn require_organizer(&self) {
+        require!(!self.organizer_renounced().get(), "Organizer renounced");
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.organizer().get() || self.admins().contains(&caller),
+            "Only organizer can call this",
+        );
+    }
+
+    /// The organizer alone, for managing admins and the organizer role itself
+    fn require_main_organizer(&self) {
+        require!(!self.organizer_renounced().get(), "Organizer renounced");
         let caller = self.blockchain().get_caller();
         require!(caller == self.organizer().get(), "Only organizer can call this");
     }
 
+    #[event("electionCreated")]
+    fn election_created_event(&self, #[indexed] election_id: u64, name: &ManagedBuffer);
+
+    /// One per ballot choice from every ballot endpoint; `voter` is the sender, a relayer for `voteAnonymous`
+    #[event("voteCast")]
+    fn vote_cast_event(&self, #[indexed] election_id: u64, #[indexed] candidate: &ManagedBuffer, voter: &ManagedAddress);
+
+    #[event("electionFinalized")]
+    fn election_finalized_event(&self, #[indexed] election_id: u64);
+
+    #[event("electionReopened")]
+    fn election_reopened_event(&self, #[indexed] election_id: u64, #[indexed] new_end_time: u64);
+
+    fn require_creator(&self) {
+        require!(!self.organizer_renounced().get(), "Organizer renounced");
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.organizer().get() || self.admins().contains(&caller) || self.creators().contains(&caller),
+            "Only organizer or creators can create elections",
+        );
+    }
+
     #[storage_mapper("organizer")]
     fn organizer(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("pendingOrganizer")]
+    fn pending_organizer(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("organizerRenounced")]
+    fn organizer_renounced(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("electionsCreated")]
+    fn elections_created(&self, organizer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("creators")]
+    fn creators(&self) -> SetMapper<ManagedAddress>;
+
+    #[storage_mapper("admins")]
+    fn admins(&self) -> SetMapper<ManagedAddress>;
+
+    #[storage_mapper("maxElectionDuration")]
+    fn max_election_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("electionOrganizer")]
+    fn election_organizer(&self, election_id: u64) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("electionCreator")]
+    fn election_creator(&self, election_id: u64) -> SingleValueMapper<ManagedAddress>;
+
     #[storage_mapper("lastElectionId")]
     fn last_election_id(&self) -> SingleValueMapper<u64>;
 
+    #[storage_mapper("votingMode")]
+    fn voting_mode(&self, election_id: u64) -> SingleValueMapper<VotingMode>;
+
     #[storage_mapper("electionInfo")]
     fn election_info(&self, id: u64) -> SingleValueMapper<ElectionInfo<Self::Api>>;
 
+    /// Same key as `election_info`, read as raw bytes
+    #[storage_mapper("electionInfo")]
+    fn election_info_raw(&self, id: u64) -> SingleValueMapper<ManagedBuffer>;
+
     #[storage_mapper("finalCandidates")]
     fn final_candidates(&self, id: u64) -> SingleValueMapper<ManagedVec<ManagedBuffer>>;
 
@@ -433,6 +4056,9 @@ pub trait VotingApp {
     #[storage_mapper("eligibleVoters")]
     fn eligible_voters(&self, id: u64) -> SetMapper<ManagedAddress>;
 
+    #[storage_mapper("merkleRegistered")]
+    fn merkle_registered(&self, election_id: u64) -> SetMapper<ManagedAddress>;
+
     #[storage_mapper("hasVoted")]
     fn has_voted(&self, id: u64) -> SetMapper<ManagedAddress>;
 
@@ -442,9 +4068,212 @@ pub trait VotingApp {
     #[storage_mapper("encryptedVotes")]
     fn encrypted_votes(&self, id: u64) -> SetMapper<ManagedBuffer>;
 
+    #[storage_mapper("candidateRoot")]
+    fn candidate_root(&self, election_id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("anonymousRoot")]
+    fn anonymous_root(&self, election_id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("openTally")]
+    fn open_tally(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("provenCandidates")]
+    fn proven_candidates(&self, election_id: u64) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("ballotOrderMode")]
+    fn ballot_order_mode(&self, election_id: u64) -> SingleValueMapper<BallotOrderMode>;
+
+    #[storage_mapper("ballotSeed")]
+    fn ballot_seed(&self, election_id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("disqualified")]
+    fn disqualified(&self, election_id: u64) -> SetMapper<ManagedBuffer>;
+
+    #[storage_mapper("finalizedAt")]
+    fn finalized_at(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("finalEligibleCount")]
+    fn final_eligible_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("notary")]
+    fn notary(&self, election_id: u64) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("resultsNotarized")]
+    fn results_notarized(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("autoRevoteOnTie")]
+    fn auto_revote_on_tie(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("revoteDuration")]
+    fn revote_duration(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("revoteElection")]
+    fn revote_election(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("writeInsEnabled")]
+    fn write_ins_enabled(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("pendingWriteIns")]
+    fn pending_write_ins(&self, election_id: u64) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[storage_mapper("writeInVotes")]
+    fn write_in_votes(&self, election_id: u64, name: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("cancelledElections")]
+    fn cancelled_elections(&self) -> UnorderedSetMapper<u64>;
+
+    /// ElectionInfo as it was when the election was cancelled, kept for `cloneElection`
+    #[storage_mapper("cancelledInfo")]
+    fn cancelled_info(&self, election_id: u64) -> SingleValueMapper<ElectionInfo<Self::Api>>;
+
+    #[storage_mapper("electionGroup")]
+    fn election_group(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("groupElections")]
+    fn group_elections(&self, group_id: u64) -> SetMapper<u64>;
+
+    #[storage_mapper("revealDuration")]
+    fn reveal_duration(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("voteCommitment")]
+    fn vote_commitment(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("revealed")]
+    fn revealed(&self, election_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("revealDeposit")]
+    fn reveal_deposit(&self, election_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("commitCount")]
+    fn commit_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("depositsSwept")]
+    fn deposits_swept(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("heldDeposits")]
+    fn held_deposits(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("sweepRound")]
+    fn sweep_round(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("commitRound")]
+    fn commit_round(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("depositBeneficiary")]
+    fn deposit_beneficiary(&self, election_id: u64) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("delegation")]
+    fn delegation(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("delegatedPower")]
+    fn delegated_power(&self, election_id: u64, delegate: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("maxDelegatedWeight")]
+    fn max_delegated_weight(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("electionPaused")]
+    fn election_paused(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("lastVoteWins")]
+    fn last_vote_wins(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("openRegistration")]
+    fn open_registration(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("restrictOrganizer")]
+    fn restrict_organizer(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    /// Candidate chosen through `vote` in open-tally elections
+    #[storage_mapper("voterChoice")]
+    fn voter_choice(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("stakeContract")]
+    fn stake_contract(&self, election_id: u64) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("voterWeight")]
+    fn voter_weight(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("eligibleCountOverride")]
+    fn eligible_count_override(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("badgeCollection")]
+    fn badge_collection(&self) -> SingleValueMapper<EsdtTokenIdentifier>;
+
+    #[storage_mapper("badgeClaimed")]
+    fn badge_claimed(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("nftGate")]
+    fn nft_gate(&self, election_id: u64) -> SingleValueMapper<EsdtTokenIdentifier>;
+
+    #[storage_mapper("requiredToken")]
+    fn required_token(&self, election_id: u64) -> SingleValueMapper<EsdtTokenIdentifier>;
+
+    #[storage_mapper("tierWeight")]
+    fn tier_weight(&self, election_id: u64, tier: u8) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("votingNft")]
+    fn voting_nft(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("castWeight")]
+    fn cast_weight(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("tallyMethod")]
+    fn tally_method(&self, election_id: u64) -> SingleValueMapper<TallyMethod>;
+
+    #[storage_mapper("abstainCount")]
+    fn abstain_count(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("quorum")]
+    fn quorum(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("quorumMet")]
+    fn quorum_met(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("allowedMethods")]
+    fn allowed_methods(&self, election_id: u64) -> SingleValueMapper<u8>;
+
+    #[storage_mapper("rankedChoice")]
+    fn ranked_choice(&self, election_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("rankedBallot")]
+    fn ranked_ballot(&self, election_id: u64, voter: &ManagedAddress) -> SingleValueMapper<ManagedVec<ManagedBuffer>>;
+
+    #[storage_mapper("rankedVoters")]
+    fn ranked_voters(&self, election_id: u64) -> VecMapper<ManagedAddress>;
+
+    #[storage_mapper("maxChoices")]
+    fn max_choices(&self, election_id: u64) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("displayThreshold")]
+    fn display_threshold(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("candidateCap")]
+    fn candidate_cap(&self, election_id: u64, candidate: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("candidateMetadata")]
+    fn candidate_metadata(&self, election_id: u64, candidate: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("resultSnapshots")]
+    fn result_snapshots(&self, election_id: u64) -> VecMapper<ResultSnapshot<Self::Api>>;
+
     #[storage_mapper("usedNullifiers")]
     fn used_nullifiers(&self, election_id: u64) -> SetMapper<ManagedBuffer>;
 
+    #[storage_mapper("minTotalWeight")]
+    fn min_total_weight(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("totalWeightedVotes")]
+    fn total_weighted_votes(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("electionDefaults")]
+    fn election_defaults(&self) -> SingleValueMapper<ElectionDefaults>;
+
+    #[storage_mapper("votesTreeSize")]
+    fn votes_tree_size(&self, election_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("votesTreeBranch")]
+    fn votes_tree_branch(&self, election_id: u64, level: u32) -> SingleValueMapper<ManagedBuffer>;
+
     #[storage_mapper("voteCounts")]
     fn vote_counts(&self, id: u64, candidate: &ManagedBuffer) -> SingleValueMapper<u64>;
 }
@@ -5,6 +5,6 @@ fn world() -> ScenarioWorld {
 }
 
 #[test]
-fn empty_go() {
+fn voting_app_go() {
     world().run("scenarios/voting_app.scen.json");
 }
@@ -9,6 +9,6 @@ fn world() -> ScenarioWorld {
 }
 
 #[test]
-fn empty_rs() {
+fn voting_app_rs() {
     world().run("scenarios/voting_app.scen.json");
 }
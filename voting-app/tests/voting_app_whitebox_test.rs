@@ -0,0 +1,7146 @@
+use ed25519_dalek::{Signer, SigningKey};
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::keccak256;
+
+use voting_app::*;
+
+const ORGANIZER: TestAddress = TestAddress::new("organizer");
+const VOTER_1: TestAddress = TestAddress::new("voter1");
+const VOTER_2: TestAddress = TestAddress::new("voter2");
+const VOTER_3: TestAddress = TestAddress::new("voter3");
+const OUTSIDER: TestAddress = TestAddress::new("outsider");
+const VOTING_APP: TestSCAddress = TestSCAddress::new("voting-app");
+const CODE_PATH: MxscPath = MxscPath::new("output/voting-app.mxsc.json");
+
+const STAKING: TestSCAddress = TestSCAddress::new("staking");
+const STAKING_CODE_PATH: MxscPath = MxscPath::new("output/mock-staking.mxsc.json");
+
+const START: u64 = 100;
+const END: u64 = 200;
+
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.register_contract(CODE_PATH, voting_app::ContractBuilder);
+
+    for account in [ORGANIZER, VOTER_1, VOTER_2, VOTER_3, OUTSIDER] {
+        world.account(account).nonce(1);
+    }
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .raw_deploy()
+        .code(CODE_PATH)
+        .new_address(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.init());
+
+    world
+}
+
+fn set_timestamp(world: &mut ScenarioWorld, timestamp: u64) {
+    world
+        .current_block()
+        .block_timestamp_seconds(TimestampSeconds::new(timestamp));
+}
+
+fn buffers(items: &[&[u8]]) -> MultiValueEncoded<DebugApi, ManagedBuffer<DebugApi>> {
+    let mut result = MultiValueEncoded::new();
+    for item in items {
+        result.push(ManagedBuffer::new_from_bytes(item));
+    }
+    result
+}
+
+fn create_election(world: &mut ScenarioWorld, candidates: &'static [&'static [u8]]) {
+    create_election_window(world, START, END, candidates);
+}
+
+fn create_election_window(
+    world: &mut ScenarioWorld,
+    start: u64,
+    end: u64,
+    candidates: &'static [&'static [u8]],
+) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let election_id = sc.create_election(
+                ManagedBuffer::from("Election"),
+                start,
+                end,
+                OptionalValue::None,
+                buffers(candidates),
+            );
+            sc.set_open_tally(election_id, true);
+        });
+}
+
+fn add_voters(world: &mut ScenarioWorld, election_id: u64, voters: &[TestAddress]) {
+    let voters = voters.to_vec();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut encoded = MultiValueEncoded::new();
+            for voter in voters {
+                encoded.push(voter.to_managed_address());
+            }
+            sc.add_voters(election_id, encoded);
+        });
+}
+
+fn vote(world: &mut ScenarioWorld, voter: TestAddress, election_id: u64, candidate: &'static str) {
+    world
+        .tx()
+        .from(voter)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(election_id, ManagedBuffer::from(candidate));
+        });
+}
+
+/// Runs an organizer call before the election starts, then moves back to its start
+fn configure_before_start(
+    world: &mut ScenarioWorld,
+    call: impl FnOnce(voting_app::ContractObj<DebugApi>),
+) {
+    set_timestamp(world, 0);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, call);
+    set_timestamp(world, START);
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut combined = left.to_vec();
+    combined.extend_from_slice(right);
+    keccak256(&combined)
+}
+
+fn hash_sorted_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    if a <= b {
+        hash_pair(a, b)
+    } else {
+        hash_pair(b, a)
+    }
+}
+
+#[test]
+fn register_candidate_with_valid_proof() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Dave"]);
+
+    let bob = keccak256(b"Bob");
+    let carol = keccak256(b"Carol");
+    let root = hash_sorted_pair(&bob, &carol);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&root));
+        });
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_candidate_with_proof(1, ManagedBuffer::from("Bob"), buffers(&[&carol]));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.candidates(1).contains(&ManagedBuffer::from("Bob")));
+            assert_eq!(sc.election_info(1).get().candidates.len(), 3);
+        });
+}
+
+#[test]
+fn register_candidate_without_valid_proof_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    let bob = keccak256(b"Bob");
+    let carol = keccak256(b"Carol");
+    let root = hash_sorted_pair(&bob, &carol);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&root));
+        });
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ExpectError(
+            4,
+            "Invalid Merkle proof - candidate not allowed",
+        ))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_candidate_with_proof(1, ManagedBuffer::from("Mallory"), buffers(&[&carol]));
+        });
+}
+
+#[test]
+fn block_time_context_returns_mocked_block() {
+    let mut world = world();
+    set_timestamp(&mut world, 12_345);
+    world.current_block().block_nonce(77);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (timestamp, nonce) = sc.get_block_time_context().into_tuple();
+            assert_eq!(timestamp, 12_345);
+            assert_eq!(nonce, 77);
+        });
+}
+
+fn create_merkle_election(world: &mut ScenarioWorld, root: [u8; 32]) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let election_id = sc.create_election_with_merkle(
+                ManagedBuffer::from("Anonymous"),
+                START,
+                END,
+                ManagedBuffer::new_from_bytes(&root),
+                buffers(&[b"Alice", b"Bob"]),
+            );
+            sc.set_open_tally(election_id, true);
+        });
+}
+
+fn create_keyless_election(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+}
+
+#[test]
+fn keyless_election_without_open_tally_rejects_votes() {
+    let mut world = world();
+    create_keyless_election(&mut world);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            voters.push(VOTER_1.to_managed_address());
+            sc.add_voters(1, voters);
+        });
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election encryption keys not set"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn keyless_election_without_open_tally_is_not_finalized_on_end() {
+    let mut world = world();
+    create_keyless_election(&mut world);
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(!sc.election_info(1).get().is_finalized);
+            assert!(!sc.is_open_tally(1));
+        });
+}
+
+#[test]
+fn open_tally_and_encryption_key_are_exclusive() {
+    let mut world = world();
+    create_keyless_election(&mut world);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_encryption_public_key(1, ManagedBuffer::from("key"));
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election has an encryption key"))
+        .whitebox(voting_app::contract_obj, |sc| sc.set_open_tally(1, true));
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses an open tally"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_encryption_public_key(2, ManagedBuffer::from("key"));
+        });
+}
+
+fn create_anonymous_election(world: &mut ScenarioWorld, root: [u8; 32]) {
+    create_election(world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let election_id = sc.last_election_id().get();
+            sc.set_anonymous_root(election_id, ManagedBuffer::new_from_bytes(&root));
+        });
+}
+
+#[test]
+fn anonymous_vote_with_fresh_nullifier() {
+    let mut world = world();
+    let leaf_1 = keccak256(b"nullifier-1");
+    let leaf_2 = keccak256(b"nullifier-2");
+    create_anonymous_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::from("nullifier-1"),
+                buffers(&[&leaf_2]),
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert!(sc
+                .used_nullifiers(1)
+                .contains(&ManagedBuffer::from("nullifier-1")));
+            assert!(sc.has_voted(1).is_empty());
+        });
+}
+
+#[test]
+fn four_leaf_tree_accepts_sorted_pair_proofs_only() {
+    let mut world = world();
+    let leaves: Vec<[u8; 32]> = [
+        b"nullifier-1",
+        b"nullifier-2",
+        b"nullifier-3",
+        b"nullifier-4",
+    ]
+    .iter()
+    .map(|nullifier| keccak256(*nullifier))
+    .collect();
+    let left = hash_sorted_pair(&leaves[0], &leaves[1]);
+    let right = hash_sorted_pair(&leaves[2], &leaves[3]);
+    create_anonymous_election(&mut world, hash_sorted_pair(&left, &right));
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::from("nullifier-3"),
+                buffers(&[&leaves[3], &left]),
+            );
+        });
+
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Invalid Merkle proof - not eligible"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Bob"),
+                ManagedBuffer::from("nullifier-1"),
+                buffers(&[&leaves[2], &right]),
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 0);
+        });
+}
+
+#[test]
+fn voter_root_leaves_cannot_vote_anonymously() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    create_merkle_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    set_timestamp(&mut world, START);
+
+    // Voter addresses are public, so they must not pass as nullifiers
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Anonymous voting not enabled"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::new_from_bytes(&VOTER_1.eval_to_array()),
+                buffers(&[&leaf_2]),
+            );
+        });
+}
+
+#[test]
+fn anonymous_root_rejects_voter_address_leaves() {
+    let mut world = world();
+    let leaf_1 = keccak256(b"nullifier-1");
+    let leaf_2 = keccak256(b"nullifier-2");
+    create_anonymous_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Invalid Merkle proof - not eligible"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::new_from_bytes(&VOTER_1.eval_to_array()),
+                buffers(&[&leaf_2]),
+            );
+        });
+}
+
+#[test]
+fn anonymous_vote_with_reused_nullifier_is_rejected() {
+    let mut world = world();
+    let leaf_1 = keccak256(b"nullifier-1");
+    let leaf_2 = keccak256(b"nullifier-2");
+    create_anonymous_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::from("nullifier-1"),
+                buffers(&[&leaf_2]),
+            );
+        });
+
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Already voted"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Bob"),
+                ManagedBuffer::from("nullifier-1"),
+                buffers(&[&leaf_2]),
+            );
+        });
+}
+
+#[test]
+fn state_counts_split_pending_active_finalized() {
+    let mut world = world();
+    create_election_window(&mut world, 10, 20, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, 10, 500, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, 300, 400, &[b"Alice", b"Bob"]);
+    set_timestamp(&mut world, 100);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (pending, active, finalized) = sc.get_state_counts().into_tuple();
+            assert_eq!((pending, active, finalized), (1, 1, 1));
+        });
+}
+
+#[test]
+fn capped_candidate_rejects_votes_once_full() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_cap(1, ManagedBuffer::from("Alice"), 2);
+        });
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Alice");
+
+    world
+        .tx()
+        .from(VOTER_3)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Candidate is full"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+
+    vote(&mut world, VOTER_3, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 2);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+        });
+}
+
+#[test]
+fn results_delta_between_snapshots() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.take_results_snapshot(1);
+        });
+
+    vote(&mut world, VOTER_2, 1, "Bob");
+    vote(&mut world, VOTER_3, 1, "Bob");
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.take_results_snapshot(1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let delta: Vec<(ManagedBuffer<DebugApi>, i64)> = sc
+                .get_results_delta(1, 1, 2)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(
+                delta,
+                vec![
+                    (ManagedBuffer::from("Alice"), 0),
+                    (ManagedBuffer::from("Bob"), 2)
+                ]
+            );
+
+            assert!(sc.get_results_delta(1, 1, 3).is_empty());
+            assert!(sc.get_results_delta(1, 0, 2).is_empty());
+        });
+}
+
+#[test]
+fn snapshots_match_candidates_by_name_after_write_in_approval() {
+    let mut world = world();
+    create_election(&mut world, &[b"Bob", b"Dave"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_ballot_order_mode(&mut world, 1, BallotOrderMode::Alphabetical);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_write_ins_enabled(1, true)
+        });
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_write_in(1, ManagedBuffer::from("Alice"));
+        });
+
+    // "Alice" sorts first once approved, shifting every other candidate's position
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.take_results_snapshot(1);
+            sc.approve_write_in(1, ManagedBuffer::from("Alice"));
+        });
+    vote(&mut world, VOTER_3, 1, "Dave");
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.take_results_snapshot(1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let delta: Vec<(ManagedBuffer<DebugApi>, i64)> = sc
+                .get_results_delta(1, 1, 2)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(
+                delta,
+                vec![
+                    (ManagedBuffer::from("Alice"), 1),
+                    (ManagedBuffer::from("Bob"), 0),
+                    (ManagedBuffer::from("Dave"), 1)
+                ]
+            );
+
+            let trend: Vec<u64> = sc
+                .get_candidate_trend(1, ManagedBuffer::from("Bob"))
+                .into_iter()
+                .map(|point| point.into_tuple().1)
+                .collect();
+            assert_eq!(trend, vec![1, 1]);
+        });
+}
+
+#[test]
+fn vote_for_proven_candidate_is_counted() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+
+    let bob = keccak256(b"Bob");
+    let carol = keccak256(b"Carol");
+    let root = hash_sorted_pair(&bob, &carol);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&root));
+        });
+
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_for_proven_candidate(1, ManagedBuffer::from("Bob"), buffers(&[&carol]));
+        });
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let results: Vec<(ManagedBuffer<DebugApi>, u64)> =
+                sc.get_election_results(1).into_iter().collect();
+            assert_eq!(
+                results,
+                vec![
+                    (ManagedBuffer::from("Alice"), 0),
+                    (ManagedBuffer::from("Bob"), 1)
+                ]
+            );
+        });
+}
+
+#[test]
+fn vote_for_unproven_candidate_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+
+    let bob = keccak256(b"Bob");
+    let carol = keccak256(b"Carol");
+    let root = hash_sorted_pair(&bob, &carol);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&root));
+        });
+
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(
+            4,
+            "Invalid Merkle proof - candidate not allowed",
+        ))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_for_proven_candidate(1, ManagedBuffer::from("Mallory"), buffers(&[&carol]));
+        });
+}
+
+#[test]
+fn turnout_over_time_buckets_snapshots() {
+    let mut world = world();
+    create_election_window(&mut world, START, 1_000, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+
+    let timeline: [(u64, TestAddress, &str); 3] = [
+        (110, VOTER_1, "Alice"),
+        (130, VOTER_2, "Bob"),
+        (250, VOTER_3, "Alice"),
+    ];
+    for (timestamp, voter, candidate) in timeline {
+        set_timestamp(&mut world, timestamp);
+        vote(&mut world, voter, 1, candidate);
+        world
+            .tx()
+            .from(ORGANIZER)
+            .to(VOTING_APP)
+            .whitebox(voting_app::contract_obj, |sc| {
+                sc.take_results_snapshot(1);
+            });
+    }
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let buckets: Vec<(u64, u64)> = sc
+                .get_turnout_over_time(1, 100)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(buckets, vec![(100, 2), (200, 3)]);
+        });
+}
+
+fn set_min_total_weight(world: &mut ScenarioWorld, election_id: u64, min_total_weight: u64) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_min_total_weight(election_id, min_total_weight);
+        });
+}
+
+#[test]
+fn finalization_succeeds_when_total_weight_reached() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_min_total_weight(&mut world, 1, 2);
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.total_weighted_votes(1).get(), 2);
+            assert!(sc.election_info(1).get().is_finalized);
+        });
+}
+
+#[test]
+fn finalization_reverts_below_total_weight() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_min_total_weight(&mut world, 1, 2);
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Minimum total weight not reached"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(1);
+        });
+}
+
+#[test]
+fn migrate_all_elections_moves_every_election_and_global_role() {
+    let mut world = world();
+    for _ in 0..3 {
+        create_election(&mut world, &[b"Alice", b"Bob"]);
+    }
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.migrate_all_elections_to(OUTSIDER.to_managed_address());
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            for id in 1..=3 {
+                assert_eq!(sc.get_election_organizer(id), OUTSIDER.to_managed_address());
+            }
+            assert_eq!(sc.organizer().get(), OUTSIDER.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Only organizer can call this"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.migrate_all_elections_to(ORGANIZER.to_managed_address());
+        });
+}
+
+#[test]
+fn eligible_but_not_registered_flags_unproven_list_voters() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    create_merkle_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_with_merkle_proof(1, buffers(&[&leaf_2]));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let pending: Vec<ManagedAddress<DebugApi>> = sc
+                .get_eligible_but_not_registered(1, 0, 10)
+                .into_iter()
+                .collect();
+            assert_eq!(
+                pending,
+                vec![VOTER_2.to_managed_address(), VOTER_3.to_managed_address()]
+            );
+
+            let second_page: Vec<ManagedAddress<DebugApi>> = sc
+                .get_eligible_but_not_registered(1, 2, 10)
+                .into_iter()
+                .collect();
+            assert_eq!(second_page, vec![VOTER_3.to_managed_address()]);
+        });
+}
+
+fn set_ballot_order_mode(world: &mut ScenarioWorld, election_id: u64, mode: BallotOrderMode) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_ballot_order_mode(election_id, mode);
+        });
+}
+
+#[test]
+fn alphabetical_ballot_order() {
+    let mut world = world();
+    create_election(&mut world, &[b"Charlie", b"Alice", b"Bob"]);
+    set_ballot_order_mode(&mut world, 1, BallotOrderMode::Alphabetical);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let ordered: Vec<ManagedBuffer<DebugApi>> =
+                sc.get_election_candidates(1).into_iter().collect();
+            assert_eq!(
+                ordered,
+                vec![
+                    ManagedBuffer::from("Alice"),
+                    ManagedBuffer::from("Bob"),
+                    ManagedBuffer::from("Charlie")
+                ]
+            );
+        });
+}
+
+#[test]
+fn randomized_ballot_order_is_stable() {
+    let mut world = world();
+    world
+        .current_block()
+        .block_random_seed(ManagedBuffer::<StaticApi>::new_from_bytes(&[7u8; 48]));
+    create_election(&mut world, &[b"Alice", b"Bob", b"Charlie", b"Dave"]);
+    set_ballot_order_mode(&mut world, 1, BallotOrderMode::Randomized);
+
+    let mut orders = Vec::new();
+    for _ in 0..2 {
+        world
+            .query()
+            .to(VOTING_APP)
+            .whitebox(voting_app::contract_obj, |sc| {
+                let ordered: Vec<Vec<u8>> = sc
+                    .get_election_candidates(1)
+                    .into_iter()
+                    .map(|candidate| candidate.to_vec())
+                    .collect();
+                orders.push(ordered);
+            });
+    }
+
+    assert_eq!(orders[0], orders[1]);
+    let mut sorted = orders[0].clone();
+    sorted.sort();
+    assert_eq!(
+        sorted,
+        vec![
+            b"Alice".to_vec(),
+            b"Bob".to_vec(),
+            b"Charlie".to_vec(),
+            b"Dave".to_vec()
+        ]
+    );
+}
+
+#[test]
+fn contract_config_reflects_global_settings() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Bob", b"Carol"]);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.migrate_all_elections_to(OUTSIDER.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.pause();
+            sc.set_max_election_duration(5_000);
+            sc.set_election_defaults(7, BallotOrderMode::Alphabetical, true);
+            sc.propose_organizer(VOTER_1.to_managed_address());
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let config = sc.get_contract_config();
+            assert_eq!(config.organizer, OUTSIDER.to_managed_address());
+            assert_eq!(config.election_count, 2);
+            assert_eq!(config.max_migration_elections, MAX_MIGRATION_ELECTIONS);
+            assert!(config.paused);
+            assert_eq!(config.max_election_duration, 5_000);
+            assert_eq!(config.min_candidates, MIN_CANDIDATES as u64);
+            assert_eq!(config.max_candidates, MAX_CANDIDATES as u64);
+            assert!(!config.organizer_renounced);
+            assert_eq!(config.pending_organizer, Some(VOTER_1.to_managed_address()));
+            let defaults = config.election_defaults.unwrap();
+            assert_eq!(defaults.min_total_weight, 7);
+            assert_eq!(defaults.ballot_order_mode, BallotOrderMode::Alphabetical);
+            assert!(defaults.write_ins_enabled);
+        });
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.renounce_organizer(ManagedBuffer::from(RENOUNCE_CONFIRMATION));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let config = sc.get_contract_config();
+            assert!(config.organizer_renounced);
+            assert_eq!(config.organizer, ManagedAddress::zero());
+        });
+}
+
+fn disqualify(
+    world: &mut ScenarioWorld,
+    election_id: u64,
+    candidate: &'static str,
+    zero_existing: bool,
+) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.disqualify_candidate(election_id, ManagedBuffer::from(candidate), zero_existing);
+        });
+}
+
+#[test]
+fn vote_for_disqualified_candidate_reverts() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    disqualify(&mut world, 1, "Alice", false);
+
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Candidate disqualified"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn results_flag_disqualified_candidates() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    disqualify(&mut world, 1, "Alice", true);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let results: Vec<(ManagedBuffer<DebugApi>, u64, bool)> = sc
+                .get_election_results_with_status(1)
+                .into_iter()
+                .map(|triple| triple.into_tuple())
+                .collect();
+            assert_eq!(
+                results,
+                vec![
+                    (ManagedBuffer::from("Alice"), 0, true),
+                    (ManagedBuffer::from("Bob"), 1, false)
+                ]
+            );
+            assert_eq!(sc.total_weighted_votes(1).get(), 1);
+        });
+}
+
+#[test]
+fn disqualify_reverts_instead_of_underflowing_the_total() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.total_weighted_votes(1).set(0u64);
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Vote count underflow"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.disqualify_candidate(1, ManagedBuffer::from("Alice"), true);
+        });
+}
+
+#[test]
+fn borda_points_handle_an_empty_candidate_list() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.borda_points(1, &ManagedVec::new()).is_empty());
+        });
+}
+
+#[test]
+fn eligibility_source_reports_list_or_none() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_voter_eligibility_source(1, VOTER_1.to_managed_address()),
+                ELIGIBILITY_LIST
+            );
+            assert_eq!(
+                sc.get_voter_eligibility_source(1, OUTSIDER.to_managed_address()),
+                ELIGIBILITY_NONE
+            );
+        });
+}
+
+fn finalize_open_tally(world: &mut ScenarioWorld, election_id: u64) {
+    set_timestamp(world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(election_id);
+        });
+}
+
+fn notarized_election_setup(notary_key: &SigningKey) -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+
+    let notary = notary_key.verifying_key().to_bytes();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_notary(1, ManagedAddress::from(&notary));
+        });
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    finalize_open_tally(&mut world, 1);
+    world
+}
+
+fn results_hash(world: &mut ScenarioWorld, election_id: u64) -> Vec<u8> {
+    let mut hash = Vec::new();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            hash = sc.get_results_hash(election_id).to_vec();
+        });
+    hash
+}
+
+#[test]
+fn notary_signature_marks_results_notarized() {
+    let notary_key = SigningKey::from_bytes(&[1u8; 32]);
+    let mut world = notarized_election_setup(&notary_key);
+    let signature = notary_key.sign(&results_hash(&mut world, 1)).to_bytes();
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.notarize_results(1, ManagedBuffer::new_from_bytes(&signature));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.is_results_notarized(1));
+        });
+}
+
+#[test]
+fn signature_from_wrong_signer_is_rejected() {
+    let notary_key = SigningKey::from_bytes(&[1u8; 32]);
+    let impostor_key = SigningKey::from_bytes(&[2u8; 32]);
+    let mut world = notarized_election_setup(&notary_key);
+    let signature = impostor_key.sign(&results_hash(&mut world, 1)).to_bytes();
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ExpectStatus(10))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.notarize_results(1, ManagedBuffer::new_from_bytes(&signature));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(!sc.is_results_notarized(1));
+        });
+}
+
+#[test]
+fn raw_election_reports_empty_mappers() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    // Simulate a partially migrated id: candidates written, ElectionInfo missing
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.candidates(2).insert(ManagedBuffer::from("Orphan"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let existing = sc.get_election_raw(1);
+            assert!(!existing.info_bytes.is_empty());
+            assert!(!existing.candidates_empty);
+            assert!(existing.eligible_voters_empty);
+
+            let partial = sc.get_election_raw(2);
+            assert!(partial.info_bytes.is_empty());
+            assert!(!partial.candidates_empty);
+            assert!(partial.eligible_voters_empty);
+            assert!(partial.has_voted_empty);
+            assert!(partial.encrypted_votes_empty);
+            assert!(partial.final_candidates_empty);
+            assert!(partial.final_counts_empty);
+        });
+}
+
+fn enable_auto_revote(world: &mut ScenarioWorld, election_id: u64) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_auto_revote_on_tie(election_id, true, 50);
+        });
+}
+
+#[test]
+fn tie_triggers_auto_revote() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    enable_auto_revote(&mut world, 1);
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_revote_election(1), 2);
+
+            let revote = sc.election_info(2).get();
+            assert_eq!(revote.start_time, END + 1);
+            assert_eq!(revote.end_time, END + 51);
+            let candidates: Vec<ManagedBuffer<DebugApi>> =
+                revote.candidates.iter().map(|c| c.clone_value()).collect();
+            assert_eq!(
+                candidates,
+                vec![ManagedBuffer::from("Alice"), ManagedBuffer::from("Bob")]
+            );
+            assert!(sc
+                .eligible_voters(2)
+                .contains(&VOTER_1.to_managed_address()));
+        });
+}
+
+#[test]
+fn revote_inherits_weights_and_candidate_caps() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            voters.push((VOTER_1.to_managed_address(), 3u64).into());
+            voters.push((VOTER_2.to_managed_address(), 3u64).into());
+            sc.add_weighted_voters(1, voters);
+            sc.set_candidate_cap(1, ManagedBuffer::from("Alice"), 10);
+        });
+    enable_auto_revote(&mut world, 1);
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_revote_election(1), 2);
+            assert_eq!(sc.voter_weight(2, &VOTER_1.to_managed_address()).get(), 3);
+            assert_eq!(sc.voter_weight(2, &VOTER_2.to_managed_address()).get(), 3);
+            assert_eq!(sc.candidate_cap(2, &ManagedBuffer::from("Alice")).get(), 10);
+        });
+}
+
+#[test]
+fn revote_inherits_merkle_registrations() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    create_merkle_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_with_merkle_proof(1, buffers(&[&leaf_2]));
+        });
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let info = sc.election_info(1).get();
+            let mut counts = ManagedVec::new();
+            counts.push(1u64);
+            counts.push(1u64);
+            let revote_id = sc.open_revote_on_tie(&info, &info.candidates, &counts);
+            assert_eq!(revote_id.into_option(), Some(2));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc
+                .merkle_registered(2)
+                .contains(&VOTER_1.to_managed_address()));
+            assert!(!sc
+                .merkle_registered(2)
+                .contains(&VOTER_2.to_managed_address()));
+        });
+}
+
+#[test]
+fn merkle_revote_accepts_votes() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    create_merkle_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    add_voters(&mut world, 1, &[VOTER_3]);
+    enable_auto_revote(&mut world, 1);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_with_merkle_proof(1, buffers(&[&leaf_2]));
+        });
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_3, 1, "Bob");
+    finalize_open_tally(&mut world, 1);
+
+    // Inherited registrations and list entries vote directly; other tree members register first
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_with_merkle_proof(2, buffers(&[&leaf_1]));
+        });
+    vote(&mut world, VOTER_1, 2, "Alice");
+    vote(&mut world, VOTER_2, 2, "Alice");
+    vote(&mut world, VOTER_3, 2, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_voting_mode(2), VotingMode::MerkleProof);
+            assert_eq!(sc.vote_counts(2, &ManagedBuffer::from("Alice")).get(), 2);
+            assert_eq!(sc.vote_counts(2, &ManagedBuffer::from("Bob")).get(), 1);
+        });
+}
+
+#[test]
+fn revote_window_is_bounded_by_max_duration() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election too long"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_max_election_duration(END - START);
+            sc.set_auto_revote_on_tie(1, true, END - START + 1);
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_auto_revote_on_tie(1, true, END - START);
+            sc.set_max_election_duration(40);
+        });
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let revote = sc.election_info(2).get();
+            assert_eq!(revote.end_time - revote.start_time, 40);
+        });
+}
+
+#[test]
+fn clear_winner_does_not_trigger_revote() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    enable_auto_revote(&mut world, 1);
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Alice");
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_revote_election(1), 0);
+            assert_eq!(sc.last_election_id().get(), 1);
+        });
+}
+
+#[test]
+fn active_elections_for_voter_skips_voted_ones() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, 500, 600, &[b"Alice", b"Bob"]);
+    for id in 1..=3 {
+        add_voters(&mut world, id, &[VOTER_1]);
+    }
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let ids: Vec<u64> = sc
+                .get_active_elections_for_voter(VOTER_1.to_managed_address())
+                .into_iter()
+                .collect();
+            assert_eq!(ids, vec![2]);
+        });
+}
+
+#[test]
+fn participation_rate_counts_eligible_elections() {
+    let mut world = world();
+    for _ in 0..3 {
+        create_election(&mut world, &[b"Alice", b"Bob"]);
+    }
+    add_voters(&mut world, 1, &[VOTER_1]);
+    add_voters(&mut world, 2, &[VOTER_1]);
+    add_voters(&mut world, 3, &[VOTER_2]);
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (voted, eligible) = sc
+                .get_participation_rate(VOTER_1.to_managed_address())
+                .into_tuple();
+            assert_eq!((voted, eligible), (1, 2));
+        });
+}
+
+fn write_in_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_write_ins_enabled(1, true);
+        });
+
+    set_timestamp(&mut world, START);
+    for voter in [VOTER_1, VOTER_2] {
+        world
+            .tx()
+            .from(voter)
+            .to(VOTING_APP)
+            .whitebox(voting_app::contract_obj, |sc| {
+                sc.vote_write_in(1, ManagedBuffer::from("Zed"));
+            });
+    }
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let pending: Vec<(ManagedBuffer<DebugApi>, u64)> = sc
+                .get_pending_write_ins(1)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(pending, vec![(ManagedBuffer::from("Zed"), 2)]);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Zed")).get(), 0);
+        });
+    world
+}
+
+#[test]
+fn approved_write_in_keeps_its_votes() {
+    let mut world = write_in_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.approve_write_in(1, ManagedBuffer::from("Zed"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.get_pending_write_ins(1).is_empty());
+            assert!(sc.candidates(1).contains(&ManagedBuffer::from("Zed")));
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Zed")).get(), 2);
+            assert_eq!(sc.total_weighted_votes(1).get(), 2);
+        });
+}
+
+#[test]
+fn rejected_write_in_voids_its_votes() {
+    let mut world = write_in_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reject_write_in(1, ManagedBuffer::from("Zed"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.get_pending_write_ins(1).is_empty());
+            assert!(!sc.candidates(1).contains(&ManagedBuffer::from("Zed")));
+            assert_eq!(sc.write_in_votes(1, &ManagedBuffer::from("Zed")).get(), 0);
+            assert_eq!(sc.total_weighted_votes(1).get(), 0);
+        });
+}
+
+#[test]
+fn ended_unfinalized_elections_report_reason() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, START, 1_000, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_min_total_weight(&mut world, 1, 5);
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    set_timestamp(&mut world, END + 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let blocked: Vec<(u64, u8)> = sc
+                .get_ended_unfinalized_elections()
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(
+                blocked,
+                vec![(1, BLOCKED_BELOW_MIN_WEIGHT), (2, BLOCKED_READY)]
+            );
+        });
+}
+
+fn vote_receipt(voter: TestAddress, ballot: &[u8]) -> [u8; 32] {
+    let mut preimage = 32u32.to_be_bytes().to_vec();
+    preimage.extend_from_slice(&voter.eval_to_array());
+    preimage.extend_from_slice(ballot);
+    keccak256(&preimage)
+}
+
+/// Off-chain reference for the receipt tree: returns the root and the proof for `index`
+fn votes_tree_proof(leaves: &[[u8; 32]], index: usize) -> ([u8; 32], Vec<[u8; 32]>) {
+    let mut level = leaves.to_vec();
+    let mut zero = [0u8; 32];
+    let mut position = index;
+    let mut proof = Vec::new();
+    for _ in 0..VOTES_TREE_DEPTH {
+        if level.len() % 2 == 1 {
+            level.push(zero);
+        }
+        proof.push(level[position ^ 1]);
+        level = level
+            .chunks(2)
+            .map(|pair| hash_sorted_pair(&pair[0], &pair[1]))
+            .collect();
+        zero = hash_sorted_pair(&zero, &zero);
+        position /= 2;
+    }
+    (level[0], proof)
+}
+
+#[test]
+fn vote_receipt_proves_against_votes_root() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    vote(&mut world, VOTER_3, 1, "Alice");
+
+    let leaves = [
+        vote_receipt(VOTER_1, b"Alice"),
+        vote_receipt(VOTER_2, b"Bob"),
+        vote_receipt(VOTER_3, b"Alice"),
+    ];
+    let (root, proof) = votes_tree_proof(&leaves, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_votes_root(1).to_vec(), root.to_vec());
+
+            let proof_refs: Vec<&[u8]> = proof.iter().map(|p| p.as_slice()).collect();
+            assert!(sc.verify_vote_inclusion(
+                1,
+                ManagedBuffer::new_from_bytes(&leaves[1]),
+                buffers(&proof_refs)
+            ));
+
+            let forged = vote_receipt(VOTER_2, b"Alice");
+            assert!(!sc.verify_vote_inclusion(
+                1,
+                ManagedBuffer::new_from_bytes(&forged),
+                buffers(&proof_refs)
+            ));
+        });
+}
+
+#[test]
+fn new_elections_inherit_defaults() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_election_defaults(5, BallotOrderMode::Alphabetical, true);
+        });
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_election_defaults(0, BallotOrderMode::Insertion, false);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.min_total_weight(1).get(), 5);
+            assert_eq!(sc.ballot_order_mode(1).get(), BallotOrderMode::Alphabetical);
+            assert!(sc.write_ins_enabled(1).get());
+        });
+}
+
+fn multi_choice_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.set_max_choices(1, 2));
+    set_timestamp(&mut world, START);
+    world
+}
+
+fn vote_multi(world: &mut ScenarioWorld, voter: TestAddress, candidates: &'static [&'static [u8]]) {
+    world
+        .tx()
+        .from(voter)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_multi(1, buffers(candidates));
+        });
+}
+
+#[test]
+fn disallowed_voting_method_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_max_choices(1, 2);
+            sc.set_allowed_methods(1, METHOD_SINGLE_CHOICE);
+        });
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Voting method not allowed"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_multi(1, buffers(&[b"Alice", b"Bob"]));
+        });
+    vote(&mut world, VOTER_2, 1, "Carol");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 0);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Carol")).get(), 1);
+        });
+}
+
+#[test]
+fn vote_multi_accepts_exactly_k_candidates() {
+    let mut world = multi_choice_setup();
+    vote_multi(&mut world, VOTER_1, &[b"Alice", b"Bob"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Carol")).get(), 0);
+            assert_eq!(sc.has_voted(1).len(), 1);
+        });
+}
+
+#[test]
+fn vote_multi_accepts_fewer_than_k_candidates() {
+    let mut world = multi_choice_setup();
+    vote_multi(&mut world, VOTER_2, &[b"Bob"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 0);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+            assert!(sc.has_voted(1).contains(&VOTER_2.to_managed_address()));
+        });
+}
+
+#[test]
+fn vote_multi_rejects_more_than_k_candidates() {
+    let mut world = multi_choice_setup();
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Too many candidates selected"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_multi(1, buffers(&[b"Alice", b"Bob", b"Carol"]));
+        });
+}
+
+#[test]
+fn vote_multi_is_rejected_in_commit_reveal_elections() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_max_choices(1, 2);
+            sc.set_reveal_window(1, 50);
+        });
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses commit-reveal"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_multi(1, buffers(&[b"Alice", b"Bob"]));
+        });
+}
+
+#[test]
+fn vote_multi_is_rejected_in_ranked_elections() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_max_choices(1, 2);
+            sc.set_ranked_choice(1, true);
+        });
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses ranked ballots"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_multi(1, buffers(&[b"Alice", b"Bob"]));
+        });
+}
+
+#[test]
+fn group_winners_summarize_finalized_elections() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Carol", b"Dave"]);
+    create_election(&mut world, &[b"Erin", b"Frank"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    add_voters(&mut world, 2, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            for election_id in 1..=3 {
+                sc.set_election_group(election_id, 7);
+            }
+        });
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    vote(&mut world, VOTER_1, 2, "Dave");
+    finalize_open_tally(&mut world, 1);
+    finalize_open_tally(&mut world, 2);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let winners: Vec<(u64, ManagedBuffer<DebugApi>, u64)> = sc
+                .get_group_winners(7)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+            assert_eq!(
+                winners,
+                vec![
+                    (1, ManagedBuffer::from("Bob"), 2),
+                    (2, ManagedBuffer::from("Dave"), 1)
+                ]
+            );
+        });
+}
+
+#[test]
+fn last_vote_wins_moves_the_tally() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_last_vote_wins(1, true)
+        });
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_1, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 0);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+            assert_eq!(sc.total_weighted_votes(1).get(), 1);
+        });
+}
+
+#[test]
+fn repeated_vote_rejected_by_default() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Already voted"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Bob"));
+        });
+}
+
+fn can_vote(
+    world: &mut ScenarioWorld,
+    voter: TestAddress,
+    candidate: &'static str,
+) -> (bool, String) {
+    let mut outcome = (false, String::new());
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (ok, reason) = sc
+                .can_vote(
+                    1,
+                    voter.to_managed_address(),
+                    ManagedBuffer::from(candidate),
+                )
+                .into_tuple();
+            outcome = (ok, String::from_utf8(reason.to_vec()).unwrap());
+        });
+    outcome
+}
+
+fn can_vote_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+}
+
+#[test]
+fn can_vote_reports_not_started() {
+    let mut world = can_vote_setup();
+    assert_eq!(
+        can_vote(&mut world, VOTER_1, "Alice"),
+        (false, "Election not started".into())
+    );
+}
+
+#[test]
+fn can_vote_reports_ended() {
+    let mut world = can_vote_setup();
+    set_timestamp(&mut world, END + 1);
+    assert_eq!(
+        can_vote(&mut world, VOTER_2, "Bob"),
+        (false, "Election ended".into())
+    );
+}
+
+#[test]
+fn add_voters_reports_only_new_addresses() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            for voter in [VOTER_2, VOTER_3, VOTER_1] {
+                voters.push(voter.to_managed_address());
+            }
+            assert_eq!(sc.add_voters(1, voters), 1);
+            assert_eq!(sc.eligible_voters(1).len(), 3);
+        });
+}
+
+#[test]
+fn remove_voter_revokes_eligibility() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.remove_voter(1, VOTER_2.to_managed_address());
+        });
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Voter not in eligible list"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.remove_voter(1, VOTER_2.to_managed_address());
+        });
+
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Not eligible to vote"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn remove_voter_who_already_voted_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Voter already voted"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.remove_voter(1, VOTER_1.to_managed_address());
+        });
+}
+
+#[test]
+fn eligibility_count_is_exact_for_list_mode() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_eligibility_count(1).into_option(), Some(3));
+        });
+}
+
+#[test]
+fn eligibility_count_is_unbounded_for_merkle_mode() {
+    let mut world = world();
+    let leaf_1 = keccak256(b"nullifier-1");
+    let leaf_2 = keccak256(b"nullifier-2");
+    create_merkle_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_eligibility_count(1).into_option(), None);
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_eligible_count_override(1, 2);
+            assert_eq!(sc.get_eligibility_count(1).into_option(), Some(2));
+        });
+}
+
+#[test]
+fn pause_all_elections_then_resume_individually() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Carol", b"Dave"]);
+    create_election_window(&mut world, END, END + 100, &[b"Erin", b"Frank"]);
+    for election_id in 1..=2 {
+        add_voters(&mut world, election_id, &[VOTER_1]);
+    }
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.pause_all_elections(), 2);
+        });
+
+    for election_id in 1..=2 {
+        world
+            .tx()
+            .from(VOTER_1)
+            .to(VOTING_APP)
+            .returns(ExpectError(4, "Election paused"))
+            .whitebox(voting_app::contract_obj, |sc| {
+                sc.vote(election_id, ManagedBuffer::from("Alice"));
+            });
+    }
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.resume_election(1));
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.election_paused(2).get());
+            assert!(!sc.election_paused(3).get());
+        });
+}
+
+#[test]
+fn contract_pause_blocks_votes_until_unpaused() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.pause());
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Contract is paused"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.unpause());
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(!sc.is_paused());
+            assert!(!sc.election_info(1).get().is_finalized);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+        });
+}
+
+fn delegate(world: &mut ScenarioWorld, voter: TestAddress, to: TestAddress) {
+    world
+        .tx()
+        .from(voter)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.delegate(1, to.to_managed_address());
+        });
+}
+
+#[test]
+fn delegation_respects_weight_cap() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_max_delegated_weight(1, 1)
+        });
+
+    delegate(&mut world, VOTER_1, VOTER_3);
+
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Delegate weight cap exceeded"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.delegate(1, VOTER_3.to_managed_address());
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_delegated_power(1, VOTER_3.to_managed_address()), 1);
+            assert!(sc.delegation(1, &VOTER_2.to_managed_address()).is_empty());
+        });
+}
+
+#[test]
+fn delegate_votes_on_behalf_of_delegator() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    delegate(&mut world, VOTER_1, VOTER_3);
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Not the delegate"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_as(1, VOTER_1.to_managed_address(), ManagedBuffer::from("Bob"));
+        });
+
+    world
+        .tx()
+        .from(VOTER_3)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_as(1, VOTER_1.to_managed_address(), ManagedBuffer::from("Bob"));
+        });
+    vote(&mut world, VOTER_3, 1, "Alice");
+
+    world
+        .tx()
+        .from(VOTER_3)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Already voted"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_as(
+                1,
+                VOTER_1.to_managed_address(),
+                ManagedBuffer::from("Alice"),
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.has_voted(1).contains(&VOTER_1.to_managed_address()));
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+        });
+}
+
+#[test]
+fn delegator_cannot_vote_directly() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_3]);
+    delegate(&mut world, VOTER_1, VOTER_3);
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Vote delegated"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn delegation_cap_counts_voter_weight() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_3]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            voters.push((VOTER_1.to_managed_address(), 3u64).into());
+            voters.push((VOTER_2.to_managed_address(), 2u64).into());
+            sc.add_weighted_voters(1, voters);
+            sc.set_max_delegated_weight(1, 4);
+        });
+
+    delegate(&mut world, VOTER_1, VOTER_3);
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Delegate weight cap exceeded"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.delegate(1, VOTER_3.to_managed_address());
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_delegated_power(1, VOTER_3.to_managed_address()), 3);
+        });
+}
+
+#[test]
+fn try_vote_returns_false_after_delegating() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    delegate(&mut world, VOTER_1, VOTER_2);
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(!sc.try_vote(1, ManagedBuffer::from("Alice")));
+        });
+}
+
+fn commit_reveal_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.set_reveal_window(1, 50));
+    set_timestamp(&mut world, START);
+    world
+}
+
+fn commit_vote(world: &mut ScenarioWorld, voter: TestAddress, candidate: &[u8], salt: &[u8]) {
+    let mut preimage = candidate.to_vec();
+    preimage.extend_from_slice(salt);
+    let commitment = keccak256(&preimage);
+    world
+        .tx()
+        .from(voter)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.commit_vote(1, ManagedBuffer::new_from_bytes(&commitment));
+        });
+}
+
+fn elections_requiring_reveal(world: &mut ScenarioWorld, voter: TestAddress) -> Vec<u64> {
+    let mut ids = Vec::new();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            ids = sc
+                .get_elections_requiring_reveal(voter.to_managed_address())
+                .into_iter()
+                .collect();
+        });
+    ids
+}
+
+#[test]
+fn pending_reveal_listed_until_revealed() {
+    let mut world = commit_reveal_setup();
+    commit_vote(&mut world, VOTER_1, b"Alice", b"salt");
+    assert!(elections_requiring_reveal(&mut world, VOTER_1).is_empty());
+
+    set_timestamp(&mut world, END + 1);
+    assert_eq!(elections_requiring_reveal(&mut world, VOTER_1), vec![1]);
+    assert!(elections_requiring_reveal(&mut world, VOTER_2).is_empty());
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reveal_vote(1, ManagedBuffer::from("Alice"), ManagedBuffer::from("salt"));
+        });
+    assert!(elections_requiring_reveal(&mut world, VOTER_1).is_empty());
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+        });
+}
+
+#[test]
+fn mismatched_reveal_is_rejected() {
+    let mut world = commit_reveal_setup();
+    commit_vote(&mut world, VOTER_1, b"Alice", b"salt");
+    set_timestamp(&mut world, END + 1);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Reveal does not match commitment"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reveal_vote(1, ManagedBuffer::from("Bob"), ManagedBuffer::from("salt"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 0);
+            assert!(!sc.revealed(1).contains(&VOTER_1.to_managed_address()));
+        });
+}
+
+#[test]
+fn cloned_election_keeps_config_but_not_votes() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_min_total_weight(&mut world, 1, 2);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.force_end_election(1);
+            assert_eq!(sc.clone_election(1, END, END + 100), 2);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let clone = sc.election_info(2).get();
+            assert_eq!(clone.candidates, sc.election_info(1).get().candidates);
+            assert_eq!((clone.start_time, clone.end_time), (END, END + 100));
+            assert!(!clone.is_finalized);
+            assert!(sc
+                .eligible_voters(2)
+                .contains(&VOTER_1.to_managed_address()));
+            assert!(sc
+                .eligible_voters(2)
+                .contains(&VOTER_2.to_managed_address()));
+            assert_eq!(sc.min_total_weight(2).get(), 2);
+            assert_eq!(sc.vote_counts(2, &ManagedBuffer::from("Alice")).get(), 0);
+            assert_eq!(sc.total_weighted_votes(2).get(), 0);
+            assert!(sc.has_voted(2).is_empty());
+        });
+}
+
+#[test]
+fn finalized_order_matches_ballot_order() {
+    let mut world = world();
+    create_election(&mut world, &[b"Carol", b"Alice", b"Bob"]);
+    set_ballot_order_mode(&mut world, 1, BallotOrderMode::Alphabetical);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Carol");
+
+    let mut ballot = Vec::new();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            ballot = sc
+                .get_election_candidates(1)
+                .into_iter()
+                .map(|candidate| candidate.to_vec())
+                .collect::<Vec<_>>();
+        });
+
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let finalized: Vec<Vec<u8>> = sc
+                .final_candidates(1)
+                .get()
+                .into_iter()
+                .map(|candidate| candidate.to_vec())
+                .collect();
+            assert_eq!(finalized, ballot);
+            assert_eq!(finalized[0], b"Alice".to_vec());
+            assert_eq!(sc.final_counts(1).get().get(2), 1);
+        });
+}
+
+fn take_snapshot(world: &mut ScenarioWorld, timestamp: u64) {
+    set_timestamp(world, timestamp);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.take_results_snapshot(1);
+        });
+}
+
+#[test]
+fn candidate_trend_follows_snapshots() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+    take_snapshot(&mut world, START + 10);
+    vote(&mut world, VOTER_2, 1, "Bob");
+    vote(&mut world, VOTER_3, 1, "Alice");
+    take_snapshot(&mut world, START + 20);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let trend: Vec<(u64, u64)> = sc
+                .get_candidate_trend(1, ManagedBuffer::from("Bob"))
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(trend, vec![(START + 10, 1), (START + 20, 2)]);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Invalid candidate"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.get_candidate_trend(1, ManagedBuffer::from("Carol"));
+        });
+}
+
+#[test]
+fn creators_can_create_elections() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_creator(VOTER_1.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let id = sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+            assert_eq!(id, 1);
+            assert_eq!(sc.get_election_organizer(1), VOTER_1.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ExpectError(
+            4,
+            "Only organizer or creators can create elections",
+        ))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+}
+
+#[test]
+fn results_csv_lists_one_line_per_candidate() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Smith, Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_timestamp(&mut world, START);
+    for voter in [VOTER_1, VOTER_2] {
+        vote(&mut world, voter, 1, "Smith, Bob");
+    }
+    vote(&mut world, VOTER_3, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let csv = String::from_utf8(sc.get_election_results_csv(1).to_vec()).unwrap();
+            assert_eq!(
+                csv.lines().collect::<Vec<_>>(),
+                vec!["Alice,1", "\"Smith, Bob\",2"]
+            );
+        });
+}
+
+#[test]
+fn display_threshold_hides_minor_candidates() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_display_threshold(1, 2)
+        });
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Alice");
+    vote(&mut world, VOTER_3, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let shown: Vec<(ManagedBuffer<DebugApi>, u64)> =
+                sc.get_election_results(1).into_iter().collect();
+            assert_eq!(shown, vec![(ManagedBuffer::from("Alice"), 2)]);
+
+            let full: Vec<(ManagedBuffer<DebugApi>, u64)> =
+                sc.get_election_results_full(1).into_iter().collect();
+            assert_eq!(
+                full,
+                vec![
+                    (ManagedBuffer::from("Alice"), 2),
+                    (ManagedBuffer::from("Bob"), 1)
+                ]
+            );
+            assert_eq!(sc.total_weighted_votes(1).get(), 3);
+        });
+}
+
+#[test]
+fn try_vote_skips_ineligible_callers() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.try_vote(1, ManagedBuffer::from("Alice")));
+        });
+
+    for voter in [OUTSIDER, VOTER_1] {
+        world
+            .tx()
+            .from(voter)
+            .to(VOTING_APP)
+            .whitebox(voting_app::contract_obj, |sc| {
+                assert!(!sc.try_vote(1, ManagedBuffer::from("Bob")));
+            });
+    }
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 0);
+        });
+}
+
+fn deposit_setup() -> (ScenarioWorld, [u8; 32]) {
+    let mut world = world();
+    for voter in [VOTER_1, VOTER_2] {
+        world.set_egld_balance(voter, 1_000u64);
+    }
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_reveal_window(1, 50);
+            sc.set_reveal_deposit(1, BigUint::from(100u64));
+        });
+    set_timestamp(&mut world, START);
+
+    let commitment = keccak256(b"Alicesalt");
+    for voter in [VOTER_1, VOTER_2] {
+        world
+            .tx()
+            .from(voter)
+            .to(VOTING_APP)
+            .egld(100)
+            .whitebox(voting_app::contract_obj, |sc| {
+                sc.commit_vote(1, ManagedBuffer::new_from_bytes(&commitment));
+            });
+    }
+    (world, commitment)
+}
+
+#[test]
+fn reveal_refunds_deposit_and_no_show_forfeits() {
+    let (mut world, _) = deposit_setup();
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reveal_vote(1, ManagedBuffer::from("Alice"), ManagedBuffer::from("salt"));
+        });
+    world.check_account(VOTER_1).balance(1_000);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Reveal window still open"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.sweep_unrevealed(1);
+        });
+
+    set_timestamp(&mut world, END + 51);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.sweep_unrevealed(1), BigUint::from(100u64));
+        });
+    world.check_account(VOTER_2).balance(900);
+    world.check_account(ORGANIZER).balance(100);
+    world.check_account(VOTING_APP).balance(0);
+}
+
+fn sweep(world: &mut ScenarioWorld, caller: TestAddress, forfeited: u64) {
+    world
+        .tx()
+        .from(caller)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.sweep_unrevealed(1), BigUint::from(forfeited));
+        });
+}
+
+#[test]
+fn sweep_after_renounce_pays_the_configuring_organizer() {
+    let (mut world, _) = deposit_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.renounce_organizer(ManagedBuffer::from(RENOUNCE_CONFIRMATION));
+        });
+
+    set_timestamp(&mut world, END + 51);
+    sweep(&mut world, OUTSIDER, 200);
+    world.check_account(ORGANIZER).balance(200);
+    world.check_account(VOTING_APP).balance(0);
+}
+
+#[test]
+fn reopen_allows_sweeping_again_without_refunding_swept_deposits() {
+    let (mut world, _) = deposit_setup();
+    world.set_egld_balance(VOTER_3, 1_000u64);
+    add_voters(&mut world, 1, &[VOTER_3]);
+    set_timestamp(&mut world, END + 51);
+    sweep(&mut world, ORGANIZER, 200);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(1);
+            sc.reopen_election(1, END + 100);
+        });
+
+    let commitment = keccak256(b"Bobsalt");
+    world
+        .tx()
+        .from(VOTER_3)
+        .to(VOTING_APP)
+        .egld(100)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.commit_vote(1, ManagedBuffer::new_from_bytes(&commitment));
+        });
+
+    set_timestamp(&mut world, END + 101);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reveal_vote(1, ManagedBuffer::from("Alice"), ManagedBuffer::from("salt"));
+        });
+    world.check_account(VOTER_1).balance(900);
+
+    set_timestamp(&mut world, END + 151);
+    sweep(&mut world, ORGANIZER, 100);
+    world.check_account(ORGANIZER).balance(300);
+    world.check_account(VOTER_3).balance(900);
+    world.check_account(VOTING_APP).balance(0);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+        });
+}
+
+#[test]
+fn commit_requires_exact_deposit() {
+    let (mut world, commitment) = deposit_setup();
+    world.set_egld_balance(VOTER_3, 1_000u64);
+    add_voters(&mut world, 1, &[VOTER_3]);
+    world
+        .tx()
+        .from(VOTER_3)
+        .to(VOTING_APP)
+        .egld(50)
+        .returns(ExpectError(4, "Payment must equal the reveal deposit"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.commit_vote(1, ManagedBuffer::new_from_bytes(&commitment));
+        });
+}
+
+#[test]
+fn election_count_tracked_per_organizer() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_creator(VOTER_1.to_managed_address());
+        });
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Bob", b"Carol"]);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Carol", b"Dave"]),
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_election_count_by_organizer(ORGANIZER.to_managed_address()),
+                2
+            );
+            assert_eq!(
+                sc.get_election_count_by_organizer(VOTER_1.to_managed_address()),
+                1
+            );
+            assert_eq!(
+                sc.get_election_count_by_organizer(OUTSIDER.to_managed_address()),
+                0
+            );
+        });
+}
+
+/// Minimal staking contract exposing `getStake`, which reverts for unknown stakers
+mod mock_staking {
+    use multiversx_sc::imports::*;
+
+    #[multiversx_sc::contract]
+    pub trait MockStaking {
+        #[init]
+        fn init(&self) {}
+
+        #[endpoint(setStake)]
+        fn set_stake(&self, staker: ManagedAddress, stake: BigUint) {
+            self.stakes(&staker).set(stake);
+        }
+
+        #[view(getStake)]
+        fn get_stake(&self, staker: ManagedAddress) -> BigUint {
+            require!(!self.stakes(&staker).is_empty(), "Unknown staker");
+            self.stakes(&staker).get()
+        }
+
+        #[storage_mapper("stakes")]
+        fn stakes(&self, staker: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    }
+}
+
+fn staking_setup() -> ScenarioWorld {
+    let mut world = world();
+    world.register_contract(STAKING_CODE_PATH, mock_staking::ContractBuilder);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .raw_deploy()
+        .code(STAKING_CODE_PATH)
+        .new_address(STAKING)
+        .whitebox(mock_staking::contract_obj, |sc| {
+            use mock_staking::MockStaking;
+            sc.init();
+            sc.set_stake(VOTER_1.to_managed_address(), BigUint::from(250u64));
+        });
+
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_stake_contract(1, STAKING.to_managed_address());
+        });
+    set_timestamp(&mut world, START);
+    world
+}
+
+#[test]
+fn stake_becomes_vote_weight() {
+    let mut world = staking_setup();
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 250);
+            assert_eq!(sc.total_weighted_votes(1).get(), 250);
+        });
+}
+
+#[test]
+fn can_vote_reports_failed_stake_query() {
+    let mut world = staking_setup();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (ok, reason) = sc
+                .can_vote(
+                    1,
+                    VOTER_2.to_managed_address(),
+                    ManagedBuffer::from("Alice"),
+                )
+                .into_tuple();
+            assert!(!ok);
+            assert_eq!(reason, ManagedBuffer::from("Stake query failed"));
+        });
+}
+
+#[test]
+fn try_vote_returns_false_when_stake_query_fails() {
+    let mut world = staking_setup();
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(!sc.try_vote(1, ManagedBuffer::from("Alice")));
+            assert!(!sc.has_voted(1).contains(&VOTER_2.to_managed_address()));
+        });
+}
+
+#[test]
+fn failed_stake_query_rejects_vote() {
+    let mut world = staking_setup();
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Stake query failed"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn stake_weighs_every_multi_choice_selection() {
+    let mut world = staking_setup();
+    configure_before_start(&mut world, |sc| sc.set_max_choices(1, 2));
+    vote_multi(&mut world, VOTER_1, &[b"Alice", b"Bob"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 250);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 250);
+        });
+}
+
+#[test]
+fn failed_stake_query_rejects_ranked_ballot() {
+    let mut world = staking_setup();
+    configure_before_start(&mut world, |sc| sc.set_ranked_choice(1, true));
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Stake query failed"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_ranked(1, buffers(&[b"Alice"]));
+        });
+}
+
+#[test]
+fn stake_at_commit_weighs_the_revealed_vote() {
+    let mut world = staking_setup();
+    configure_before_start(&mut world, |sc| sc.set_reveal_window(1, 50));
+    commit_vote(&mut world, VOTER_1, b"Alice", b"salt");
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reveal_vote(1, ManagedBuffer::from("Alice"), ManagedBuffer::from("salt"));
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 250);
+        });
+}
+
+#[test]
+fn finalization_preconditions_list_every_blocker() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_min_total_weight(&mut world, 1, 2);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let blockers: Vec<ManagedBuffer<DebugApi>> =
+                sc.get_finalization_preconditions(1).into_iter().collect();
+            assert_eq!(
+                blockers,
+                vec![
+                    ManagedBuffer::from("Election not yet ended"),
+                    ManagedBuffer::from("Minimum total weight not reached")
+                ]
+            );
+        });
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_finalization_preconditions(1).len(), 1);
+        });
+}
+
+fn ranked_setup(candidates: &'static [&'static [u8]]) -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, candidates);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3, OUTSIDER]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.set_ranked_choice(1, true));
+    set_timestamp(&mut world, START);
+    world
+}
+
+fn vote_ranked(world: &mut ScenarioWorld, voter: TestAddress, ranking: &'static [&'static [u8]]) {
+    world
+        .tx()
+        .from(voter)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_ranked(1, buffers(ranking));
+        });
+}
+
+fn head_to_head(world: &mut ScenarioWorld, a: &'static str, b: &'static str) -> (u64, u64) {
+    let mut counts = (0, 0);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            counts = sc
+                .get_head_to_head(1, ManagedBuffer::from(a), ManagedBuffer::from(b))
+                .into_tuple();
+        });
+    counts
+}
+
+#[test]
+fn head_to_head_counts_pairwise_preferences() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_1, &[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Bob", b"Alice"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Carol"]);
+
+    assert_eq!(head_to_head(&mut world, "Alice", "Bob"), (1, 1));
+    assert_eq!(head_to_head(&mut world, "Alice", "Carol"), (2, 1));
+    assert_eq!(head_to_head(&mut world, "Carol", "Bob"), (1, 2));
+}
+
+fn condorcet_winner(world: &mut ScenarioWorld) -> Option<Vec<u8>> {
+    let mut winner = None;
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            winner = sc
+                .get_condorcet_winner(1)
+                .into_option()
+                .map(|name| name.to_vec());
+        });
+    winner
+}
+
+#[test]
+fn condorcet_winner_beats_everyone() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_1, &[b"Bob", b"Alice", b"Carol"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Carol", b"Bob", b"Alice"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Alice", b"Bob", b"Carol"]);
+
+    assert_eq!(condorcet_winner(&mut world), Some(b"Bob".to_vec()));
+}
+
+#[test]
+fn condorcet_cycle_has_no_winner() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_1, &[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Bob", b"Carol", b"Alice"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Carol", b"Alice", b"Bob"]);
+
+    assert_eq!(condorcet_winner(&mut world), None);
+}
+
+#[test]
+fn ranked_ballot_is_stored_in_preference_order() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_1, &[b"Carol", b"Alice"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let ballot: Vec<Vec<u8>> = sc
+                .ranked_ballot(1, &VOTER_1.to_managed_address())
+                .get()
+                .iter()
+                .map(|choice| choice.to_vec())
+                .collect();
+            assert_eq!(ballot, vec![b"Carol".to_vec(), b"Alice".to_vec()]);
+        });
+}
+
+#[test]
+fn ranking_with_duplicate_candidate_is_rejected() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Duplicate candidate"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_ranked(1, buffers(&[b"Alice", b"Bob", b"Alice"]));
+        });
+}
+
+#[test]
+fn plain_vote_is_rejected_in_ranked_election() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob"]);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses ranked ballots"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn ranked_choice_is_stored_as_voting_mode() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob"]);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_voting_mode(1), VotingMode::RankedChoice);
+        });
+}
+
+#[test]
+fn merkle_election_cannot_switch_to_ranked_ballots() {
+    let mut world = world();
+    create_merkle_election(&mut world, keccak256(b"nullifier-1"));
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses another voting mode"))
+        .whitebox(voting_app::contract_obj, |sc| sc.set_ranked_choice(1, true));
+}
+
+#[test]
+fn irv_transfers_eliminated_preferences() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_1, &[b"Alice"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Carol", b"Alice"]);
+    vote_ranked(&mut world, OUTSIDER, &[b"Carol", b"Bob"]);
+
+    // No first-round majority; Bob loses the tie for last place and his ballot moves to Carol
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let winner = sc
+                .compute_irv_winner(1)
+                .into_option()
+                .map(|name| name.to_vec());
+            assert_eq!(winner, Some(b"Carol".to_vec()));
+        });
+}
+
+#[test]
+fn borda_ordering_differs_from_plurality() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_tally_method(1, TallyMethod::Borda)
+        });
+    vote_ranked(&mut world, VOTER_1, &[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Bob", b"Carol", b"Alice"]);
+    vote_ranked(&mut world, OUTSIDER, &[b"Carol", b"Bob", b"Alice"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let borda: Vec<(ManagedBuffer<DebugApi>, u64)> = sc
+                .get_borda_results(1)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(
+                borda,
+                vec![
+                    (ManagedBuffer::from("Bob"), 5),
+                    (ManagedBuffer::from("Alice"), 4),
+                    (ManagedBuffer::from("Carol"), 3)
+                ]
+            );
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 2);
+        });
+
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let counts: Vec<u64> = sc.final_counts(1).get().iter().collect();
+            assert_eq!(counts, vec![4, 5, 3]);
+        });
+}
+
+#[test]
+fn voter_count_at_finalization_survives_purging() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    finalize_open_tally(&mut world, 1);
+    purge_after_reopen_window(&mut world);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.eligible_voters(1).is_empty());
+            assert_eq!(sc.get_voter_count_at_finalization(1), 3);
+        });
+}
+
+#[test]
+fn extend_election_keeps_voting_open() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.extend_election(1, END + 50)
+        });
+
+    set_timestamp(&mut world, END + 10);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.election_info(1).get().end_time, END + 50);
+        });
+}
+
+#[test]
+fn extend_election_to_earlier_end_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "New end time must be later"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.extend_election(1, END - 10)
+        });
+}
+
+#[test]
+fn add_candidates_before_start_skips_duplicates() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_candidates(1, buffers(&[b"Bob", b"Alice", b"Carol"]));
+        });
+
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Carol");
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let candidates: Vec<Vec<u8>> = sc
+                .election_info(1)
+                .get()
+                .candidates
+                .iter()
+                .map(|c| c.to_vec())
+                .collect();
+            assert_eq!(
+                candidates,
+                vec![b"Alice".to_vec(), b"Bob".to_vec(), b"Carol".to_vec()]
+            );
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Carol")).get(), 1);
+        });
+}
+
+#[test]
+fn add_candidates_after_start_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election already started"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_candidates(1, buffers(&[b"Bob"]));
+        });
+}
+
+fn reopen_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    finalize_open_tally(&mut world, 1);
+    world
+}
+
+#[test]
+fn reopen_within_window_resumes_voting() {
+    let mut world = reopen_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reopen_election(1, END + 100);
+        });
+    vote(&mut world, VOTER_2, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let info = sc.election_info(1).get();
+            assert!(!info.is_finalized);
+            assert_eq!(info.end_time, END + 100);
+            assert!(sc.final_candidates(1).is_empty());
+            assert_eq!(sc.total_weighted_votes(1).get(), 2);
+        });
+}
+
+#[test]
+fn reopen_after_window_is_rejected() {
+    let mut world = reopen_setup();
+    set_timestamp(&mut world, END + 2 + REOPEN_WINDOW_SECONDS);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Reopen window expired"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reopen_election(1, END + 2 * REOPEN_WINDOW_SECONDS);
+        });
+}
+
+#[test]
+fn reopen_respects_max_election_duration() {
+    let mut world = reopen_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election too long"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_max_election_duration(END - START + 50);
+            sc.reopen_election(1, END + 51);
+        });
+}
+
+#[test]
+fn reopen_rejects_an_end_time_before_the_start() {
+    let mut world = reopen_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Start time must be before end time"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.election_info(1)
+                .update(|info| info.start_time = END + 200);
+            sc.reopen_election(1, END + 100);
+        });
+}
+
+#[test]
+fn method_stats_count_each_configured_method() {
+    let mut world = world();
+    for _ in 0..5 {
+        create_election(&mut world, &[b"Alice", b"Bob"]);
+    }
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Sealed"),
+                START,
+                END,
+                OptionalValue::Some(ManagedBuffer::from("public-key")),
+                buffers(&[b"Alice", b"Bob"]),
+            );
+            sc.set_max_choices(2, 2);
+            sc.set_ranked_choice(3, true);
+            sc.set_reveal_window(4, 50);
+            sc.set_max_choices(5, 1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let stats = sc.get_election_method_stats();
+            assert_eq!(stats.single_choice, 2);
+            assert_eq!(stats.multi_choice, 1);
+            assert_eq!(stats.ranked, 1);
+            assert_eq!(stats.commit_reveal, 1);
+            assert_eq!(stats.encrypted, 1);
+        });
+}
+
+const HERO_NFT: &[u8] = b"HERO-abcdef";
+
+fn nft_gate_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_nft_gate(1, EsdtTokenIdentifier::from(HERO_NFT));
+            sc.set_tier_weight(1, 3, 25);
+        });
+    for (voter, tier) in [(VOTER_1, 3u8), (VOTER_2, 7u8)] {
+        world.set_nft_balance_all_properties(
+            voter,
+            HERO_NFT,
+            5u64,
+            1u64,
+            vec![tier, 0xff],
+            0u64,
+            None::<TestAddress>,
+            None,
+            None,
+            &[],
+        );
+    }
+    set_timestamp(&mut world, START);
+    world
+}
+
+#[test]
+fn nft_tier_sets_vote_weight() {
+    let mut world = nft_gate_setup();
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.register_voting_nft(1, 5));
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 25);
+        });
+}
+
+#[test]
+fn nft_without_weighted_tier_is_rejected() {
+    let mut world = nft_gate_setup();
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "No qualifying NFT"))
+        .whitebox(voting_app::contract_obj, |sc| sc.register_voting_nft(1, 5));
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "No qualifying NFT"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn can_vote_reports_missing_voting_nft() {
+    let mut world = nft_gate_setup();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (ok, reason) = sc
+                .can_vote(
+                    1,
+                    VOTER_1.to_managed_address(),
+                    ManagedBuffer::from("Alice"),
+                )
+                .into_tuple();
+            assert!(!ok);
+            assert_eq!(reason, ManagedBuffer::from("No qualifying NFT"));
+        });
+}
+
+fn expect_no_qualifying_nft(
+    world: &mut ScenarioWorld,
+    call: impl FnOnce(voting_app::ContractObj<DebugApi>),
+) {
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "No qualifying NFT"))
+        .whitebox(voting_app::contract_obj, call);
+}
+
+#[test]
+fn vote_multi_requires_a_voting_nft() {
+    let mut world = nft_gate_setup();
+    configure_before_start(&mut world, |sc| sc.set_max_choices(1, 2));
+    expect_no_qualifying_nft(&mut world, |sc| {
+        sc.vote_multi(1, buffers(&[b"Alice"]));
+    });
+}
+
+#[test]
+fn vote_ranked_requires_a_voting_nft() {
+    let mut world = nft_gate_setup();
+    configure_before_start(&mut world, |sc| sc.set_ranked_choice(1, true));
+    expect_no_qualifying_nft(&mut world, |sc| {
+        sc.vote_ranked(1, buffers(&[b"Alice"]));
+    });
+}
+
+#[test]
+fn vote_write_in_requires_a_voting_nft() {
+    let mut world = nft_gate_setup();
+    configure_before_start(&mut world, |sc| sc.set_write_ins_enabled(1, true));
+    expect_no_qualifying_nft(&mut world, |sc| {
+        sc.vote_write_in(1, ManagedBuffer::from("Zed"));
+    });
+}
+
+#[test]
+fn vote_for_proven_candidate_requires_a_voting_nft() {
+    let mut world = nft_gate_setup();
+    let root = hash_sorted_pair(&keccak256(b"Bob"), &keccak256(b"Carol"));
+    configure_before_start(&mut world, |sc| {
+        sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&root))
+    });
+    let bob = keccak256(b"Bob");
+    expect_no_qualifying_nft(&mut world, |sc| {
+        sc.vote_for_proven_candidate(1, ManagedBuffer::from("Carol"), buffers(&[&bob]));
+    });
+}
+
+#[test]
+fn commit_vote_requires_a_voting_nft() {
+    let mut world = nft_gate_setup();
+    configure_before_start(&mut world, |sc| sc.set_reveal_window(1, 50));
+    expect_no_qualifying_nft(&mut world, |sc| {
+        sc.commit_vote(1, ManagedBuffer::new_from_bytes(&keccak256(b"Alicesalt")));
+    });
+}
+
+#[test]
+fn upcoming_elections_are_sorted_by_start_time() {
+    let mut world = world();
+    create_election_window(&mut world, 300, 400, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, 150, 400, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, 50, 400, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, 200, 400, &[b"Alice", b"Bob"]);
+    set_timestamp(&mut world, START);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let ids: Vec<u64> = sc
+                .get_upcoming_elections(10)
+                .into_iter()
+                .map(|info| info.id)
+                .collect();
+            assert_eq!(ids, vec![2, 4, 1]);
+
+            let ids: Vec<u64> = sc
+                .get_upcoming_elections(2)
+                .into_iter()
+                .map(|info| info.id)
+                .collect();
+            assert_eq!(ids, vec![2, 4]);
+        });
+}
+
+const BADGE: TestTokenIdentifier = TestTokenIdentifier::new("BADGE-123456");
+
+fn badge_setup() -> ScenarioWorld {
+    let mut world = world();
+    world.set_esdt_local_roles(VOTING_APP, BADGE.as_bytes(), &[EsdtLocalRole::NftCreate]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_badge_collection(BADGE.to_token_identifier());
+        });
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    finalize_open_tally(&mut world, 1);
+    world
+}
+
+#[test]
+fn voter_claims_exactly_one_badge() {
+    let mut world = badge_setup();
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.claim_badge(1), 1);
+        });
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Badge already claimed"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.claim_badge(1);
+        });
+
+    world
+        .check_account(VOTER_1)
+        .esdt_nft_balance_and_attributes(BADGE, 1, 1, 1u64);
+}
+
+#[test]
+fn non_voter_cannot_claim_badge() {
+    let mut world = badge_setup();
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Did not vote"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.claim_badge(1);
+        });
+}
+
+#[test]
+fn lifecycle_and_vote_events_are_emitted() {
+    let mut world = world();
+    let logs = world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].topics, vec![b"electionCreated".to_vec(), vec![1]]);
+    assert_eq!(logs[0].data, vec![b"Election".to_vec()]);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.set_open_tally(1, true));
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    let logs = world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+    assert_eq!(logs.len(), 1);
+    assert_eq!(
+        logs[0].topics,
+        vec![b"voteCast".to_vec(), vec![1], b"Alice".to_vec()]
+    );
+    assert_eq!(logs[0].data, vec![VOTER_1.eval_to_array().to_vec()]);
+
+    set_timestamp(&mut world, END + 1);
+    let logs = world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.end_election(1);
+        });
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].topics, vec![b"electionFinalized".to_vec(), vec![1]]);
+}
+
+#[test]
+fn preview_finalize_reports_results_and_blockers() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_min_total_weight(&mut world, 1, 3);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Alice");
+    set_timestamp(&mut world, END + 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let preview = sc.preview_finalize(1);
+            let candidates: Vec<Vec<u8>> = preview.candidates.iter().map(|c| c.to_vec()).collect();
+            assert_eq!(candidates, vec![b"Alice".to_vec(), b"Bob".to_vec()]);
+            assert_eq!(preview.counts.iter().collect::<Vec<u64>>(), vec![2, 0]);
+            let blockers: Vec<Vec<u8>> = preview.blockers.iter().map(|b| b.to_vec()).collect();
+            assert_eq!(blockers, vec![b"Minimum total weight not reached".to_vec()]);
+            assert!(!sc.election_info(1).get().is_finalized);
+        });
+}
+
+#[test]
+fn weighted_and_unweighted_voters_share_an_election() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_3]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            voters.push((VOTER_1.to_managed_address(), 40u64).into());
+            voters.push((VOTER_2.to_managed_address(), 25u64).into());
+            assert_eq!(sc.add_weighted_voters(1, voters), 2);
+        });
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    vote(&mut world, VOTER_3, 1, "Bob");
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(
+                sc.final_counts(1).get().iter().collect::<Vec<u64>>(),
+                vec![40, 26]
+            );
+            assert_eq!(sc.total_weighted_votes(1).get(), 66);
+        });
+}
+
+#[test]
+fn overflowing_weighted_tally_reverts() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            voters.push((VOTER_1.to_managed_address(), u64::MAX).into());
+            voters.push((VOTER_2.to_managed_address(), 1u64).into());
+            sc.add_weighted_voters(1, voters);
+        });
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Vote count overflow"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Bob"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.total_weighted_votes(1).get(), u64::MAX);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 0);
+        });
+}
+
+#[test]
+fn renounced_organizer_locks_administration() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Invalid confirmation"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.renounce_organizer(ManagedBuffer::from("yes"));
+        });
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.renounce_organizer(ManagedBuffer::from(RENOUNCE_CONFIRMATION));
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer renounced"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer renounced"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            voters.push(VOTER_2.to_managed_address());
+            sc.add_voters(1, voters);
+        });
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.is_organizer_renounced());
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert_eq!(sc.get_contract_config().organizer, ManagedAddress::zero());
+        });
+}
+
+#[test]
+fn change_vote_moves_the_counted_choice() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Alice");
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.change_vote(1, ManagedBuffer::from("Bob"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+            assert_eq!(sc.total_weighted_votes(1).get(), 2);
+        });
+}
+
+#[test]
+fn change_vote_to_same_candidate_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Same candidate"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.change_vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn audit_bundle_matches_individual_views() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_min_total_weight(&mut world, 1, 2);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election not finalized"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.get_audit_bundle(1);
+        });
+
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let bundle = sc.get_audit_bundle(1);
+            assert_eq!(bundle.info.id, 1);
+            assert!(bundle.info.is_finalized);
+            assert_eq!(bundle.candidates, sc.final_candidates(1).get());
+            assert_eq!(bundle.counts, sc.final_counts(1).get());
+            assert_eq!(bundle.results_hash, sc.get_results_hash(1));
+            assert_eq!(bundle.votes_root, Some(sc.get_votes_root(1)));
+            assert_eq!(bundle.ballots_cast, 2);
+            assert_eq!(
+                bundle.eligible_at_finalization,
+                sc.get_voter_count_at_finalization(1)
+            );
+            assert!(bundle.min_weight_reached);
+            assert_eq!(bundle.finalized_at, END + 1);
+        });
+}
+
+#[test]
+fn get_winner_returns_clear_winner() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    vote(&mut world, VOTER_3, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (winner, count) = sc.get_winner(1).into_tuple();
+            assert_eq!(winner, ManagedBuffer::from("Bob"));
+            assert_eq!(count, 2);
+        });
+
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (winner, count) = sc.get_winner(1).into_tuple();
+            assert_eq!(winner, ManagedBuffer::from("Bob"));
+            assert_eq!(count, 2);
+            let winners: Vec<Vec<u8>> = sc.get_winners(1).into_iter().map(|w| w.to_vec()).collect();
+            assert_eq!(winners, vec![b"Bob".to_vec()]);
+        });
+}
+
+#[test]
+fn get_winners_lists_a_two_way_tie() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+    vote(&mut world, VOTER_2, 1, "Carol");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (winner, count) = sc.get_winner(1).into_tuple();
+            assert_eq!(winner, ManagedBuffer::from("Bob"));
+            assert_eq!(count, 1);
+            let winners: Vec<Vec<u8>> = sc.get_winners(1).into_iter().map(|w| w.to_vec()).collect();
+            assert_eq!(winners, vec![b"Bob".to_vec(), b"Carol".to_vec()]);
+        });
+}
+
+#[test]
+fn turnout_counts_voted_and_eligible() {
+    let mut world = world();
+    let extra_1 = TestAddress::new("voter4");
+    let extra_2 = TestAddress::new("voter5");
+    for account in [extra_1, extra_2] {
+        world.account(account).nonce(1);
+    }
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(
+        &mut world,
+        1,
+        &[VOTER_1, VOTER_2, VOTER_3, extra_1, extra_2],
+    );
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, extra_2, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_turnout(1).into_tuple(), (2, 5));
+        });
+
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_turnout(1).into_tuple(), (2, 5));
+        });
+}
+
+fn quorum_setup(quorum: u64) -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.set_quorum(1, quorum));
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    world
+}
+
+#[test]
+fn quorum_met_is_recorded_at_finalization() {
+    let mut world = quorum_setup(2);
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.is_quorum_met(1));
+            assert!(sc.quorum_met(1).get());
+        });
+}
+
+#[test]
+fn anonymous_ballots_count_toward_quorum() {
+    let mut world = world();
+    let leaf_1 = keccak256(b"nullifier-1");
+    let leaf_2 = keccak256(b"nullifier-2");
+    create_anonymous_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.set_quorum(1, 2));
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Bob"),
+                ManagedBuffer::from("nullifier-1"),
+                buffers(&[&leaf_2]),
+            );
+            assert!(sc.is_quorum_met(1));
+        });
+
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.quorum_met(1).get())
+        });
+}
+
+#[test]
+fn quorum_not_met_leaves_results_non_binding() {
+    let mut world = quorum_setup(3);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| assert!(!sc.is_quorum_met(1)));
+
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.election_info(1).get().is_finalized);
+            assert!(!sc.is_quorum_met(1));
+        });
+}
+
+#[test]
+fn elections_paged_returns_bounded_slices() {
+    let mut world = world();
+    for _ in 0..5 {
+        create_election(&mut world, &[b"Alice", b"Bob"]);
+    }
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let page = |start_id: u64, limit: u64| -> Vec<u64> {
+                sc.get_elections_paged(start_id, limit)
+                    .into_iter()
+                    .map(|info| info.id)
+                    .collect()
+            };
+            assert_eq!(page(1, 2), vec![1, 2]);
+            assert_eq!(page(3, 2), vec![3, 4]);
+            assert_eq!(page(5, 2), vec![5]);
+            assert!(page(6, 2).is_empty());
+            assert_eq!(page(0, 10), vec![1, 2, 3, 4, 5]);
+        });
+}
+
+#[test]
+fn organizer_handoff_requires_acceptance() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.propose_organizer(VOTER_1.to_managed_address());
+        });
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Not the pending organizer"))
+        .whitebox(voting_app::contract_obj, |sc| sc.accept_organizer());
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_organizer(), ORGANIZER.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.accept_organizer());
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_organizer(), VOTER_1.to_managed_address());
+            assert!(sc.get_pending_organizer().is_none());
+        });
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Only organizer can call this"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.propose_organizer(VOTER_2.to_managed_address());
+        });
+}
+
+#[test]
+fn abstaining_voter_cannot_vote_afterwards() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.abstain(1));
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Already voted"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+    vote(&mut world, VOTER_2, 1, "Bob");
+
+    finalize_open_tally(&mut world, 1);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_abstentions(1), 1);
+            assert_eq!(sc.get_turnout(1).into_tuple(), (2, 2));
+            assert_eq!(sc.total_weighted_votes(1).get(), 1);
+        });
+}
+
+#[test]
+fn cancel_election_before_start_removes_it() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Carol", b"Dave"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.cancel_election(1));
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.is_cancelled(1));
+            assert!(!sc.is_cancelled(2));
+            assert!(sc.election_info(1).is_empty());
+            assert!(sc.eligible_voters(1).is_empty());
+            let ids: Vec<u64> = sc
+                .get_all_elections()
+                .into_iter()
+                .map(|info| info.id)
+                .collect();
+            assert_eq!(ids, vec![2]);
+            assert_eq!(sc.get_state_counts().into_tuple(), (1, 0, 0));
+        });
+}
+
+#[test]
+fn cancelled_election_can_be_cloned() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_max_choices(1, 2);
+            sc.cancel_election(1);
+            let clone_id = sc.clone_election(1, START + 50, END + 50);
+
+            let info = sc.election_info(clone_id).get();
+            assert_eq!(info.name, ManagedBuffer::from("Election"));
+            assert_eq!((info.start_time, info.end_time), (START + 50, END + 50));
+            assert!(sc
+                .candidates(clone_id)
+                .contains(&ManagedBuffer::from("Bob")));
+            assert!(sc.open_tally(clone_id).get());
+            assert_eq!(sc.max_choices(clone_id).get(), 2);
+            assert!(sc.is_cancelled(1));
+            assert!(sc.election_info(1).is_empty());
+        });
+}
+
+#[test]
+fn cancel_election_after_start_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election already started"))
+        .whitebox(voting_app::contract_obj, |sc| sc.cancel_election(1));
+}
+
+#[test]
+fn has_voted_and_is_eligible_views() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let status = |election_id: u64, voter: TestAddress| {
+                (
+                    sc.is_eligible(election_id, voter.to_managed_address()),
+                    sc.has_voted_view(election_id, voter.to_managed_address()),
+                )
+            };
+            assert_eq!(status(1, VOTER_1), (true, true));
+            assert_eq!(status(1, VOTER_2), (true, false));
+            assert_eq!(status(1, OUTSIDER), (false, false));
+            assert_eq!(status(7, VOTER_1), (false, false));
+        });
+}
+
+#[test]
+fn voter_choice_follows_vote_and_change_vote() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let choice = sc.get_voter_choice(1, VOTER_1.to_managed_address());
+            assert_eq!(choice.map(|c| c.to_vec()), Some(b"Alice".to_vec()));
+            assert!(sc
+                .get_voter_choice(1, VOTER_2.to_managed_address())
+                .is_none());
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.change_vote(1, ManagedBuffer::from("Bob"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let choice = sc.get_voter_choice(1, VOTER_1.to_managed_address());
+            assert_eq!(choice.map(|c| c.to_vec()), Some(b"Bob".to_vec()));
+        });
+}
+
+#[test]
+fn create_election_at_candidate_cap_is_accepted() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut candidates = MultiValueEncoded::new();
+            for i in 0..MAX_CANDIDATES {
+                candidates.push(ManagedBuffer::from(format!("Candidate {i}").as_bytes()));
+            }
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                candidates,
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.election_info(1).get().candidates.len(), MAX_CANDIDATES);
+        });
+}
+
+#[test]
+fn create_election_over_candidate_cap_is_rejected() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Too many candidates"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut candidates = MultiValueEncoded::new();
+            for i in 0..=sc.max_candidates() {
+                candidates.push(ManagedBuffer::from(format!("Candidate {i}").as_bytes()));
+            }
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                candidates,
+            );
+        });
+}
+
+#[test]
+fn results_batch_covers_finalized_and_live_elections() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Carol", b"Dave"]);
+    create_election_window(&mut world, START, END + 100, &[b"Erin", b"Frank"]);
+    for election_id in 1..=3 {
+        add_voters(&mut world, election_id, &[VOTER_1, VOTER_2]);
+    }
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Alice");
+    vote(&mut world, VOTER_1, 2, "Dave");
+    vote(&mut world, VOTER_1, 3, "Frank");
+    finalize_open_tally(&mut world, 1);
+    finalize_open_tally(&mut world, 2);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut ids = MultiValueEncoded::new();
+            for election_id in [1u64, 7, 2, 3] {
+                ids.push(election_id);
+            }
+
+            let mut rows = Vec::new();
+            for entry in sc.get_results_batch(ids) {
+                for (candidate, count) in entry.candidates.iter().zip(entry.counts.iter()) {
+                    rows.push((entry.election_id, candidate.to_vec(), count));
+                }
+            }
+
+            assert_eq!(
+                rows,
+                vec![
+                    (1, b"Alice".to_vec(), 2),
+                    (1, b"Bob".to_vec(), 0),
+                    (2, b"Carol".to_vec(), 0),
+                    (2, b"Dave".to_vec(), 1),
+                    (3, b"Erin".to_vec(), 0),
+                    (3, b"Frank".to_vec(), 1),
+                ]
+            );
+        });
+}
+
+#[test]
+fn pending_voters_exclude_those_who_voted() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3, OUTSIDER]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_2, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let addresses = |voters: MultiValueEncoded<DebugApi, ManagedAddress<DebugApi>>| {
+                voters
+                    .into_iter()
+                    .map(|voter| voter.to_address())
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                addresses(sc.get_pending_voters(1)),
+                vec![
+                    VOTER_1.to_address(),
+                    VOTER_3.to_address(),
+                    OUTSIDER.to_address()
+                ]
+            );
+            assert_eq!(
+                addresses(sc.get_pending_voters_paged(1, 1, 1)),
+                vec![VOTER_3.to_address()]
+            );
+            assert!(addresses(sc.get_pending_voters_paged(1, 3, 5)).is_empty());
+        });
+}
+
+#[test]
+fn pending_voters_empty_without_list_or_after_full_turnout() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 2, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 2, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.get_pending_voters(1).is_empty());
+            assert!(sc.get_pending_voters(2).is_empty());
+        });
+}
+
+#[test]
+fn self_registration_in_open_election() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_open_registration(1, true)
+        });
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.register_self(1));
+    set_timestamp(&mut world, START);
+    vote(&mut world, OUTSIDER, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc
+                .eligible_voters(1)
+                .contains(&OUTSIDER.to_managed_address()));
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+        });
+}
+
+#[test]
+fn self_registration_closed_by_default() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Registration closed"))
+        .whitebox(voting_app::contract_obj, |sc| sc.register_self(1));
+}
+
+#[test]
+fn creator_is_kept_when_elections_are_migrated() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_creator(VOTER_1.to_managed_address());
+        });
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.migrate_elections_chunk(VOTER_2.to_managed_address(), 1, 1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_election_organizer(1), VOTER_2.to_managed_address());
+            assert_eq!(
+                sc.get_election_creator(1).into_option(),
+                Some(VOTER_1.to_managed_address())
+            );
+        });
+}
+
+#[test]
+fn admins_cannot_migrate_election_ownership() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_admin(VOTER_1.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Only organizer can call this"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.migrate_elections_chunk(VOTER_1.to_managed_address(), 1, 1);
+        });
+}
+
+#[test]
+fn admins_create_elections_until_removed() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_admin(VOTER_1.to_managed_address());
+            assert!(sc.is_admin(VOTER_1.to_managed_address()));
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let id = sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+            sc.add_voters(
+                id,
+                MultiValueEncoded::from(ManagedVec::from_single_item(VOTER_2.to_managed_address())),
+            );
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Only organizer can call this"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_admin(VOTER_2.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.remove_admin(VOTER_1.to_managed_address());
+            assert!(!sc.is_admin(VOTER_1.to_managed_address()));
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(
+            4,
+            "Only organizer or creators can create elections",
+        ))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+}
+
+#[test]
+fn organizer_cannot_be_made_a_removable_admin() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Already the organizer"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_admin(ORGANIZER.to_managed_address());
+        });
+}
+
+#[test]
+fn create_election_with_empty_candidate_name_is_rejected() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Candidate name cannot be empty"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b""]),
+            );
+        });
+}
+
+#[test]
+fn create_election_with_overlong_candidate_name_is_rejected() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Candidate name too long"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            let name = vec![b'x'; voting_app::MAX_CANDIDATE_NAME_LEN + 1];
+            sc.create_election_with_merkle(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                ManagedBuffer::from(&[0u8; 32][..]),
+                buffers(&[b"Alice", &name]),
+            );
+        });
+}
+
+#[test]
+fn create_election_with_whitespace_candidate_name_is_rejected() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Candidate name cannot be empty"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"   "]),
+            );
+        });
+}
+
+#[test]
+fn candidate_name_at_max_length_is_accepted() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let name = vec![b'x'; voting_app::MAX_CANDIDATE_NAME_LEN];
+            let election_id = sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", &name]),
+            );
+            assert_eq!(sc.candidates(election_id).len(), 2);
+        });
+}
+
+#[test]
+fn candidate_name_one_byte_over_max_length_is_rejected() {
+    let mut world = world();
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Candidate name too long"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            let name = vec![b'x'; voting_app::MAX_CANDIDATE_NAME_LEN + 1];
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", &name]),
+            );
+        });
+}
+
+#[test]
+fn election_info_reads_back_a_single_election() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, START + 10, END + 10, &[b"Carol", b"Dave"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let info = sc.get_election_info(2);
+            assert_eq!(info.id, 2);
+            assert_eq!(info.name.to_vec(), b"Election".to_vec());
+            assert_eq!((info.start_time, info.end_time), (START + 10, END + 10));
+            assert!(!info.is_finalized);
+            assert_eq!(
+                info.candidates
+                    .iter()
+                    .map(|c| c.to_vec())
+                    .collect::<Vec<_>>(),
+                vec![b"Carol".to_vec(), b"Dave".to_vec()]
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election does not exist"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.get_election_info(3);
+        });
+}
+
+#[test]
+fn election_duration_is_capped() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_max_election_duration(),
+                voting_app::DEFAULT_MAX_ELECTION_DURATION
+            );
+            sc.set_max_election_duration(END - START);
+        });
+
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election too long"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END + 1,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_max_election_duration(), END - START);
+            assert_eq!(sc.last_election_id().get(), 1);
+        });
+}
+
+#[test]
+fn candidate_votes_live_and_finalized() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2, VOTER_3]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_candidate_votes(1, ManagedBuffer::from("Alice")), 2);
+            assert_eq!(sc.get_candidate_votes(1, ManagedBuffer::from("Bob")), 0);
+        });
+
+    vote(&mut world, VOTER_3, 1, "Bob");
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_candidate_votes(1, ManagedBuffer::from("Alice")), 2);
+            assert_eq!(sc.get_candidate_votes(1, ManagedBuffer::from("Bob")), 1);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Unknown candidate"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.get_candidate_votes(1, ManagedBuffer::from("Carol"));
+        });
+}
+
+#[test]
+fn candidate_votes_unknown_candidate_in_live_election() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Unknown candidate"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.get_candidate_votes(1, ManagedBuffer::from("Carol"));
+        });
+}
+
+#[test]
+fn short_proof_element_is_rejected() {
+    let mut world = world();
+    let leaf_1 = keccak256(b"nullifier-1");
+    let leaf_2 = keccak256(b"nullifier-2");
+    create_anonymous_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Proof element must be 32 bytes"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::from("nullifier-1"),
+                buffers(&[&leaf_2[..31]]),
+            );
+        });
+}
+
+#[test]
+fn purged_election_keeps_final_results() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, VOTER_2, 1, "Bob");
+    finalize_open_tally(&mut world, 1);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Reopen window still open"))
+        .whitebox(voting_app::contract_obj, |sc| sc.purge_election(1));
+
+    set_timestamp(&mut world, END + 2 + voting_app::REOPEN_WINDOW_SECONDS);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.purge_election(1));
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let results: Vec<(Vec<u8>, u64)> = sc
+                .get_election_results(1)
+                .into_iter()
+                .map(|(candidate, count)| (candidate.to_vec(), count))
+                .collect();
+            assert_eq!(results, vec![(b"Alice".to_vec(), 1), (b"Bob".to_vec(), 1)]);
+            assert!(sc.candidates(1).is_empty());
+            assert!(sc.eligible_voters(1).is_empty());
+            assert!(sc.has_voted(1).is_empty());
+            assert!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).is_empty());
+            let voter_1 = VOTER_1.to_managed_address();
+            assert!(sc.voter_choice(1, &voter_1).is_empty());
+            assert!(sc.cast_weight(1, &voter_1).is_empty());
+        });
+}
+
+fn purge_after_reopen_window(world: &mut ScenarioWorld) {
+    set_timestamp(world, END + 2 + voting_app::REOPEN_WINDOW_SECONDS);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.purge_election(1));
+}
+
+#[test]
+fn purge_clears_ranked_ballots_and_weights() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.add_weighted_voters(1, single_weighted_address(VOTER_1, 3));
+        });
+    vote_ranked(&mut world, VOTER_1, &[b"Bob", b"Alice"]);
+    finalize_open_tally(&mut world, 1);
+    purge_after_reopen_window(&mut world);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let voter_1 = VOTER_1.to_managed_address();
+            assert!(sc.ranked_ballot(1, &voter_1).is_empty());
+            assert!(sc.ranked_voters(1).is_empty());
+            assert!(sc.cast_weight(1, &voter_1).is_empty());
+            assert!(sc.voter_weight(1, &voter_1).is_empty());
+        });
+}
+
+#[test]
+fn purge_clears_merkle_ballots_and_registrations() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let election_id = sc.create_election_with_merkle(
+                ManagedBuffer::from("Merkle"),
+                START,
+                END,
+                ManagedBuffer::new_from_bytes(&hash_sorted_pair(&leaf_1, &leaf_2)),
+                buffers(&[b"Alice", b"Bob"]),
+            );
+            sc.set_encryption_public_key(election_id, ManagedBuffer::from("public-key"));
+        });
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_with_merkle_proof(1, buffers(&[&leaf_1]));
+        });
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_with_merkle(
+                1,
+                ManagedBuffer::from("nullifier-1"),
+                ManagedBuffer::from("ciphertext"),
+                buffers(&[&leaf_2]),
+            );
+        });
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.publish_results(1, MultiValueEncoded::new());
+        });
+    purge_after_reopen_window(&mut world);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.encrypted_votes(1).is_empty());
+            assert!(sc.used_nullifiers(1).is_empty());
+            assert!(sc.merkle_registered(1).is_empty());
+        });
+}
+
+#[test]
+fn purge_clears_proven_candidate_counts() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+
+    let bob = keccak256(b"Bob");
+    let carol = keccak256(b"Carol");
+    let root = hash_sorted_pair(&bob, &carol);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&root));
+        });
+
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_for_proven_candidate(1, ManagedBuffer::from("Carol"), buffers(&[&bob]));
+        });
+    finalize_open_tally(&mut world, 1);
+
+    set_timestamp(&mut world, END + 2 + voting_app::REOPEN_WINDOW_SECONDS);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.purge_election(1));
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.vote_counts(1, &ManagedBuffer::from("Carol")).is_empty());
+            assert!(sc.proven_candidates(1).is_empty());
+            let results: Vec<(ManagedBuffer<DebugApi>, u64)> =
+                sc.get_election_results(1).into_iter().collect();
+            assert!(results.contains(&(ManagedBuffer::from("Carol"), 1)));
+        });
+}
+
+#[test]
+fn accept_organizer_requires_a_pending_handoff() {
+    let mut world = world();
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Not the pending organizer"))
+        .whitebox(voting_app::contract_obj, |sc| sc.accept_organizer());
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_organizer(), ORGANIZER.to_managed_address());
+        });
+}
+
+#[test]
+fn migrate_all_cancels_pending_organizer() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.propose_organizer(VOTER_1.to_managed_address());
+            sc.migrate_all_elections_to(OUTSIDER.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Not the pending organizer"))
+        .whitebox(voting_app::contract_obj, |sc| sc.accept_organizer());
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_organizer(), OUTSIDER.to_managed_address());
+            assert!(sc.get_pending_organizer().is_none());
+        });
+}
+
+#[test]
+fn migrate_all_rejects_zero_address() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Invalid organizer"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.migrate_all_elections_to(ManagedAddress::zero());
+        });
+}
+
+#[test]
+fn migrate_chunk_rejects_zero_address() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Invalid organizer"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.migrate_elections_chunk(ManagedAddress::zero(), 1, 1);
+        });
+}
+
+fn single_address(address: TestAddress) -> MultiValueEncoded<DebugApi, ManagedAddress<DebugApi>> {
+    let mut result = MultiValueEncoded::new();
+    result.push(address.to_managed_address());
+    result
+}
+
+fn single_weighted_address(
+    address: TestAddress,
+    weight: u64,
+) -> MultiValueEncoded<DebugApi, MultiValue2<ManagedAddress<DebugApi>, u64>> {
+    let mut result = MultiValueEncoded::new();
+    result.push((address.to_managed_address(), weight).into());
+    result
+}
+
+/// One call per endpoint gated by `require_organizer`, `require_main_organizer` or `require_creator`
+fn organizer_only_calls() -> Vec<fn(voting_app::ContractObj<DebugApi>)> {
+    vec![
+        |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        },
+        |sc| {
+            sc.create_election_with_merkle(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                ManagedBuffer::new_from_bytes(&[0u8; 32]),
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        },
+        |sc| {
+            sc.add_creator(OUTSIDER.to_managed_address());
+        },
+        |sc| {
+            sc.remove_creator(OUTSIDER.to_managed_address());
+        },
+        |sc| {
+            sc.add_admin(OUTSIDER.to_managed_address());
+        },
+        |sc| {
+            sc.remove_admin(VOTER_3.to_managed_address());
+        },
+        |sc| {
+            let _ = sc.clone_election(1, START, END);
+        },
+        |sc| {
+            sc.set_max_election_duration(1_000);
+        },
+        |sc| {
+            sc.set_election_defaults(1, BallotOrderMode::Alphabetical, true);
+        },
+        |sc| {
+            sc.set_encryption_public_key(1, ManagedBuffer::from("key"));
+        },
+        |sc| {
+            sc.set_open_tally(1, true);
+        },
+        |sc| {
+            let _ = sc.add_voters(1, single_address(VOTER_2));
+        },
+        |sc| {
+            let _ = sc.add_weighted_voters(1, single_weighted_address(VOTER_2, 2));
+        },
+        |sc| {
+            sc.set_restrict_organizer(1, true);
+        },
+        |sc| {
+            sc.set_open_registration(1, true);
+        },
+        |sc| {
+            sc.set_eligible_count_override(1, 10);
+        },
+        |sc| {
+            sc.remove_voter(1, VOTER_1.to_managed_address());
+        },
+        |sc| {
+            sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&[0u8; 32]));
+        },
+        |sc| {
+            sc.set_anonymous_root(1, ManagedBuffer::new_from_bytes(&[0u8; 32]));
+        },
+        |sc| {
+            sc.add_candidates(1, buffers(&[b"Carol"]));
+        },
+        |sc| {
+            sc.set_candidate_metadata(1, ManagedBuffer::from("Alice"), ManagedBuffer::from("bio"));
+        },
+        |sc| {
+            sc.set_ballot_order_mode(1, BallotOrderMode::Alphabetical);
+        },
+        |sc| {
+            sc.set_max_choices(1, 2);
+        },
+        |sc| {
+            sc.set_last_vote_wins(1, true);
+        },
+        |sc| {
+            sc.set_max_delegated_weight(1, 5);
+        },
+        |sc| {
+            sc.set_reveal_window(1, 50);
+        },
+        |sc| {
+            sc.set_display_threshold(1, 1);
+        },
+        |sc| {
+            sc.set_reveal_deposit(1, BigUint::from(1u64));
+        },
+        |sc| {
+            sc.set_stake_contract(1, OUTSIDER.to_managed_address());
+        },
+        |sc| {
+            sc.set_nft_gate(1, GOV_TOKEN.to_token_identifier());
+        },
+        |sc| {
+            sc.set_required_token(1, GOV_TOKEN.to_token_identifier());
+        },
+        |sc| {
+            sc.set_tier_weight(1, 1, 2);
+        },
+        |sc| {
+            sc.set_allowed_methods(1, METHOD_SINGLE_CHOICE);
+        },
+        |sc| {
+            sc.set_ranked_choice(1, true);
+        },
+        |sc| {
+            sc.set_tally_method(1, TallyMethod::Borda);
+        },
+        |sc| {
+            sc.set_candidate_cap(1, ManagedBuffer::from("Alice"), 1);
+        },
+        |sc| {
+            sc.disqualify_candidate(1, ManagedBuffer::from("Alice"), false);
+        },
+        |sc| {
+            sc.set_notary(1, OUTSIDER.to_managed_address());
+        },
+        |sc| {
+            sc.set_write_ins_enabled(1, true);
+        },
+        |sc| {
+            sc.approve_write_in(1, ManagedBuffer::from("Zed"));
+        },
+        |sc| {
+            sc.reject_write_in(1, ManagedBuffer::from("Zed"));
+        },
+        |sc| {
+            sc.set_min_total_weight(1, 1);
+        },
+        |sc| {
+            sc.set_quorum(1, 1);
+        },
+        |sc| {
+            sc.take_results_snapshot(1);
+        },
+        |sc| {
+            sc.migrate_all_elections_to(OUTSIDER.to_managed_address());
+        },
+        |sc| {
+            sc.propose_organizer(OUTSIDER.to_managed_address());
+        },
+        |sc| {
+            sc.renounce_organizer(ManagedBuffer::from(RENOUNCE_CONFIRMATION));
+        },
+        |sc| {
+            sc.migrate_elections_chunk(OUTSIDER.to_managed_address(), 1, 1);
+        },
+        |sc| {
+            sc.set_auto_revote_on_tie(1, true, 50);
+        },
+        |sc| {
+            sc.set_election_group(1, 1);
+        },
+        |sc| {
+            let _ = sc.end_election(1);
+        },
+        |sc| {
+            sc.end_election_batch(MultiValueEncoded::new());
+        },
+        |sc| {
+            sc.pause_election(1);
+        },
+        |sc| {
+            sc.resume_election(1);
+        },
+        |sc| {
+            sc.pause();
+        },
+        |sc| {
+            sc.unpause();
+        },
+        |sc| {
+            let _ = sc.pause_all_elections();
+        },
+        |sc| {
+            sc.purge_election(1);
+        },
+        |sc| {
+            sc.reopen_election(1, END + 100);
+        },
+        |sc| {
+            sc.cancel_election(1);
+        },
+        |sc| {
+            sc.extend_election(1, END + 100);
+        },
+        |sc| {
+            sc.force_end_election(1);
+        },
+        |sc| {
+            sc.set_badge_collection(GOV_TOKEN.to_token_identifier());
+        },
+        |sc| {
+            sc.publish_results(1, MultiValueEncoded::new());
+        },
+    ]
+}
+
+fn renounced_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_admin(VOTER_3.to_managed_address());
+            sc.renounce_organizer(ManagedBuffer::from(RENOUNCE_CONFIRMATION));
+        });
+    world
+}
+
+fn expect_every_organizer_call_renounced(world: &mut ScenarioWorld, caller: TestAddress) {
+    for call in organizer_only_calls() {
+        world
+            .tx()
+            .from(caller)
+            .to(VOTING_APP)
+            .returns(ExpectError(4, "Organizer renounced"))
+            .whitebox(voting_app::contract_obj, call);
+    }
+}
+
+#[test]
+fn renounced_organizer_cannot_call_any_organizer_endpoint() {
+    let mut world = renounced_setup();
+    expect_every_organizer_call_renounced(&mut world, ORGANIZER);
+}
+
+#[test]
+fn admins_cannot_call_any_organizer_endpoint_after_renounce() {
+    let mut world = renounced_setup();
+    expect_every_organizer_call_renounced(&mut world, VOTER_3);
+}
+
+#[test]
+fn creators_cannot_create_elections_after_renounce() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.add_creator(OUTSIDER.to_managed_address());
+            sc.renounce_organizer(ManagedBuffer::from(RENOUNCE_CONFIRMATION));
+        });
+    world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer renounced"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(&[b"Alice", b"Bob"]),
+            );
+        });
+}
+
+#[test]
+fn listed_weight_counts_at_reveal() {
+    let mut world = commit_reveal_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.add_weighted_voters(1, single_weighted_address(VOTER_1, 4));
+        });
+    commit_vote(&mut world, VOTER_1, b"Alice", b"salt");
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reveal_vote(1, ManagedBuffer::from("Alice"), ManagedBuffer::from("salt"));
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 4);
+            assert_eq!(sc.total_weighted_votes(1).get(), 4);
+        });
+}
+
+#[test]
+fn ranked_tabulations_count_voter_weight() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.add_weighted_voters(1, single_weighted_address(VOTER_1, 3));
+        });
+    vote_ranked(&mut world, VOTER_1, &[b"Bob", b"Alice"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Alice", b"Bob"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Alice", b"Bob"]);
+
+    assert_eq!(head_to_head(&mut world, "Bob", "Alice"), (3, 2));
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 3);
+            assert_eq!(
+                sc.compute_irv_winner(1).into_option(),
+                Some(ManagedBuffer::from("Bob"))
+            );
+            let borda: Vec<(ManagedBuffer<DebugApi>, u64)> = sc
+                .get_borda_results(1)
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(
+                borda,
+                vec![
+                    (ManagedBuffer::from("Bob"), 8),
+                    (ManagedBuffer::from("Alice"), 7),
+                    (ManagedBuffer::from("Carol"), 0),
+                ]
+            );
+        });
+}
+
+#[test]
+fn approved_write_in_counts_voter_weight() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let _ = sc.add_weighted_voters(1, single_weighted_address(VOTER_1, 6));
+            sc.set_write_ins_enabled(1, true);
+        });
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_write_in(1, ManagedBuffer::from("Zed"));
+        });
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.approve_write_in(1, ManagedBuffer::from("Zed"));
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Zed")).get(), 6);
+            assert_eq!(sc.total_weighted_votes(1).get(), 6);
+        });
+}
+
+/// (candidate topic, voter data) of each `voteCast` log
+fn vote_casts(logs: &[multiversx_sc_scenario::scenario_model::Log]) -> Vec<(Vec<u8>, Vec<u8>)> {
+    logs.iter()
+        .filter(|log| log.topics[0] == b"voteCast")
+        .map(|log| (log.topics[2].clone(), log.data[0].clone()))
+        .collect()
+}
+
+fn voter_data(voter: TestAddress) -> Vec<u8> {
+    voter.eval_to_array().to_vec()
+}
+
+#[test]
+fn vote_multi_emits_vote_cast_per_selection() {
+    let mut world = multi_choice_setup();
+    let logs = world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_multi(1, buffers(&[b"Alice", b"Carol"]));
+        });
+    assert_eq!(
+        vote_casts(&logs),
+        vec![
+            (b"Alice".to_vec(), voter_data(VOTER_1)),
+            (b"Carol".to_vec(), voter_data(VOTER_1)),
+        ]
+    );
+}
+
+#[test]
+fn vote_ranked_emits_vote_cast_for_first_preference() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob"]);
+    let logs = world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_ranked(1, buffers(&[b"Bob", b"Alice"]));
+        });
+    assert_eq!(
+        vote_casts(&logs),
+        vec![(b"Bob".to_vec(), voter_data(VOTER_1))]
+    );
+}
+
+#[test]
+fn vote_write_in_emits_vote_cast() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_write_ins_enabled(1, true)
+        });
+    set_timestamp(&mut world, START);
+
+    let logs = world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_write_in(1, ManagedBuffer::from("Zed"));
+        });
+    assert_eq!(
+        vote_casts(&logs),
+        vec![(b"Zed".to_vec(), voter_data(VOTER_1))]
+    );
+}
+
+#[test]
+fn vote_for_proven_candidate_emits_vote_cast() {
+    let mut world = proven_candidate_setup();
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+
+    let bob = keccak256(b"Bob");
+    let logs = world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_for_proven_candidate(1, ManagedBuffer::from("Carol"), buffers(&[&bob]));
+        });
+    assert_eq!(
+        vote_casts(&logs),
+        vec![(b"Carol".to_vec(), voter_data(VOTER_1))]
+    );
+}
+
+#[test]
+fn reveal_vote_emits_vote_cast() {
+    let mut world = commit_reveal_setup();
+    commit_vote(&mut world, VOTER_1, b"Alice", b"salt");
+    set_timestamp(&mut world, END + 1);
+
+    let logs = world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.reveal_vote(1, ManagedBuffer::from("Alice"), ManagedBuffer::from("salt"));
+        });
+    assert_eq!(
+        vote_casts(&logs),
+        vec![(b"Alice".to_vec(), voter_data(VOTER_1))]
+    );
+}
+
+#[test]
+fn vote_with_merkle_emits_vote_cast() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let election_id = sc.create_election_with_merkle(
+                ManagedBuffer::from("Merkle"),
+                START,
+                END,
+                ManagedBuffer::new_from_bytes(&hash_sorted_pair(&leaf_1, &leaf_2)),
+                buffers(&[b"Alice", b"Bob"]),
+            );
+            sc.set_encryption_public_key(election_id, ManagedBuffer::from("public-key"));
+        });
+    set_timestamp(&mut world, START);
+
+    let logs = world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_with_merkle(
+                1,
+                ManagedBuffer::from("nullifier-1"),
+                ManagedBuffer::from("ciphertext"),
+                buffers(&[&leaf_2]),
+            );
+        });
+    assert_eq!(
+        vote_casts(&logs),
+        vec![(b"ciphertext".to_vec(), voter_data(VOTER_1))]
+    );
+}
+
+#[test]
+fn vote_anonymous_emits_vote_cast_from_the_relayer() {
+    let mut world = world();
+    let leaf_1 = keccak256(b"nullifier-1");
+    let leaf_2 = keccak256(b"nullifier-2");
+    create_anonymous_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    set_timestamp(&mut world, START);
+
+    let logs = world
+        .tx()
+        .from(OUTSIDER)
+        .to(VOTING_APP)
+        .returns(ReturnsLogs)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_anonymous(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::from("nullifier-1"),
+                buffers(&[&leaf_2]),
+            );
+        });
+    assert_eq!(
+        vote_casts(&logs),
+        vec![(b"Alice".to_vec(), voter_data(OUTSIDER))]
+    );
+}
+
+#[test]
+fn condorcet_winner_counts_partial_ballots() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_1, &[b"Alice", b"Bob"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Bob", b"Alice"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Carol", b"Bob"]);
+
+    assert_eq!(condorcet_winner(&mut world), Some(b"Bob".to_vec()));
+}
+
+#[test]
+fn pairwise_matrix_matches_head_to_head() {
+    let mut world = ranked_setup(&[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_1, &[b"Alice", b"Bob", b"Carol"]);
+    vote_ranked(&mut world, VOTER_2, &[b"Bob", b"Alice"]);
+    vote_ranked(&mut world, VOTER_3, &[b"Carol"]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let candidates = sc.election_info(1).get().candidates;
+            let matrix = sc.pairwise_matrix(1, &candidates);
+            for (a, first) in candidates.iter().enumerate() {
+                for (b, second) in candidates.iter().enumerate() {
+                    if a != b {
+                        let (wins, _) = sc.pairwise_counts(1, &first, &second);
+                        assert_eq!(matrix.get(a * candidates.len() + b), wins);
+                    }
+                }
+            }
+        });
+}
+
+#[test]
+fn can_vote_reports_not_eligible() {
+    let mut world = can_vote_setup();
+    set_timestamp(&mut world, START);
+    assert_eq!(
+        can_vote(&mut world, OUTSIDER, "Alice"),
+        (false, "Not eligible to vote".into())
+    );
+}
+
+#[test]
+fn can_vote_reports_already_voted() {
+    let mut world = can_vote_setup();
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+    assert_eq!(
+        can_vote(&mut world, VOTER_1, "Bob"),
+        (false, "Already voted".into())
+    );
+}
+
+#[test]
+fn can_vote_reports_invalid_candidate() {
+    let mut world = can_vote_setup();
+    set_timestamp(&mut world, START);
+    assert_eq!(
+        can_vote(&mut world, VOTER_1, "Carol"),
+        (false, "Invalid candidate".into())
+    );
+}
+
+#[test]
+fn can_vote_accepts_valid_vote() {
+    let mut world = can_vote_setup();
+    set_timestamp(&mut world, START);
+    assert_eq!(
+        can_vote(&mut world, VOTER_1, "Alice"),
+        (true, String::new())
+    );
+}
+
+/// Bob is both registered and in the candidate root; Carol is only in the root
+fn proven_candidate_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    let root = hash_sorted_pair(&keccak256(b"Bob"), &keccak256(b"Carol"));
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_root(1, ManagedBuffer::new_from_bytes(&root));
+        });
+    world
+}
+
+fn vote_for_carol(world: &mut ScenarioWorld, voter: TestAddress) {
+    let bob = keccak256(b"Bob");
+    world
+        .tx()
+        .from(voter)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_for_proven_candidate(1, ManagedBuffer::from("Carol"), buffers(&[&bob]));
+        });
+}
+
+#[test]
+fn vote_for_proven_candidate_respects_candidate_cap() {
+    let mut world = proven_candidate_setup();
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_2]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_cap(1, ManagedBuffer::from("Bob"), 1);
+        });
+
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+
+    let carol = keccak256(b"Carol");
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Candidate is full"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_for_proven_candidate(1, ManagedBuffer::from("Bob"), buffers(&[&carol]));
+        });
+}
+
+#[test]
+fn vote_for_proven_candidate_counts_voter_weight() {
+    let mut world = proven_candidate_setup();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut voters = MultiValueEncoded::new();
+            voters.push(MultiValue2::from((VOTER_1.to_managed_address(), 5u64)));
+            sc.add_weighted_voters(1, voters);
+        });
+
+    set_timestamp(&mut world, START);
+    vote_for_carol(&mut world, VOTER_1);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Carol")).get(), 5);
+            assert_eq!(sc.total_weighted_votes(1).get(), 5);
+        });
+}
+
+#[test]
+fn vote_for_proven_candidate_is_rejected_in_commit_reveal_elections() {
+    let mut world = proven_candidate_setup();
+    add_voters(&mut world, 1, &[VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| sc.set_reveal_window(1, 50));
+
+    set_timestamp(&mut world, START);
+    let bob = keccak256(b"Bob");
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses commit-reveal"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_for_proven_candidate(1, ManagedBuffer::from("Carol"), buffers(&[&bob]));
+        });
+}
+
+#[test]
+fn election_count_increments_on_create() {
+    let mut world = world();
+    let election_count = |world: &mut ScenarioWorld| {
+        let mut count = 0;
+        world
+            .query()
+            .to(VOTING_APP)
+            .whitebox(voting_app::contract_obj, |sc| {
+                count = sc.get_election_count()
+            });
+        count
+    };
+
+    assert_eq!(election_count(&mut world), 0);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    assert_eq!(election_count(&mut world), 1);
+    create_election(&mut world, &[b"Carol", b"Dave"]);
+    assert_eq!(election_count(&mut world), 2);
+}
+
+#[test]
+fn candidate_metadata_set_before_start() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_metadata(
+                1,
+                ManagedBuffer::from("Alice"),
+                ManagedBuffer::from("ipfs://alice.json"),
+            );
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_candidate_metadata(1, ManagedBuffer::from("Alice"))
+                    .to_vec(),
+                b"ipfs://alice.json".to_vec()
+            );
+            assert!(sc
+                .get_candidate_metadata(1, ManagedBuffer::from("Bob"))
+                .is_empty());
+        });
+
+    set_timestamp(&mut world, START);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election already started"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_candidate_metadata(1, ManagedBuffer::from("Bob"), ManagedBuffer::from("{}"));
+        });
+}
+
+#[test]
+fn end_election_batch_skips_running_elections() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election_window(&mut world, START, END + 100, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut ids = MultiValueEncoded::new();
+            for election_id in 1..=4u64 {
+                ids.push(election_id);
+            }
+            sc.end_election_batch(ids);
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let finalized: Vec<bool> = (1..=4u64)
+                .map(|id| sc.election_info(id).get().is_finalized)
+                .collect();
+            assert_eq!(finalized, vec![true, true, false, true]);
+            assert_eq!(
+                sc.final_counts(1).get().iter().collect::<Vec<u64>>(),
+                vec![0, 1]
+            );
+        });
+}
+
+#[test]
+fn end_election_batch_is_capped() {
+    let mut world = world();
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Too many elections"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut ids = MultiValueEncoded::new();
+            for election_id in 1..=MAX_END_BATCH + 1 {
+                ids.push(election_id);
+            }
+            sc.end_election_batch(ids);
+        });
+}
+
+#[test]
+fn force_ended_and_naturally_ended_elections_tabulate_alike() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    for election_id in 1..=2 {
+        add_voters(&mut world, election_id, &[VOTER_1, VOTER_2, VOTER_3]);
+    }
+    set_timestamp(&mut world, START);
+    for election_id in 1..=2 {
+        vote(&mut world, VOTER_1, election_id, "Carol");
+        vote(&mut world, VOTER_2, election_id, "Alice");
+        vote(&mut world, VOTER_3, election_id, "Carol");
+    }
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.force_end_election(1);
+            let _ = sc.end_election(1);
+        });
+    finalize_open_tally(&mut world, 2);
+
+    assert_eq!(final_results(&mut world, 1), final_results(&mut world, 2));
+    assert_eq!(final_results(&mut world, 1).1, vec![1, 0, 2]);
+}
+
+fn final_results(world: &mut ScenarioWorld, election_id: u64) -> (Vec<Vec<u8>>, Vec<u64>) {
+    let mut results = (Vec::new(), Vec::new());
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            results.0 = sc
+                .final_candidates(election_id)
+                .get()
+                .iter()
+                .map(|candidate| candidate.to_vec())
+                .collect();
+            results.1 = sc.final_counts(election_id).get().iter().collect();
+        });
+    results
+}
+
+#[test]
+fn batch_ended_and_singly_ended_elections_tabulate_alike() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    create_election(&mut world, &[b"Alice", b"Bob", b"Carol"]);
+    for election_id in 1..=2 {
+        add_voters(&mut world, election_id, &[VOTER_1, VOTER_2]);
+    }
+    set_timestamp(&mut world, START);
+    for election_id in 1..=2 {
+        vote(&mut world, VOTER_1, election_id, "Bob");
+        vote(&mut world, VOTER_2, election_id, "Bob");
+    }
+
+    set_timestamp(&mut world, END + 1);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut ids = MultiValueEncoded::new();
+            ids.push(1u64);
+            sc.end_election_batch(ids);
+            let _ = sc.end_election(2);
+        });
+
+    assert_eq!(final_results(&mut world, 1), final_results(&mut world, 2));
+    assert_eq!(final_results(&mut world, 1).1, vec![0, 2, 0]);
+}
+
+#[test]
+fn plain_vote_on_merkle_election_is_rejected() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_merkle_election(&mut world, keccak256(b"nullifier-1"));
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses Merkle proofs"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(2, ManagedBuffer::from("Alice"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_voting_mode(1), VotingMode::DirectVoting);
+            assert_eq!(sc.get_voting_mode(2), VotingMode::MerkleProof);
+        });
+}
+
+fn expect_too_few_candidates(world: &mut ScenarioWorld, candidates: &'static [&'static [u8]]) {
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election must have at least two candidates"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.create_election(
+                ManagedBuffer::from("Election"),
+                START,
+                END,
+                OptionalValue::None,
+                buffers(candidates),
+            );
+        });
+}
+
+#[test]
+fn create_election_rejects_zero_candidates() {
+    let mut world = world();
+    expect_too_few_candidates(&mut world, &[]);
+}
+
+#[test]
+fn create_election_rejects_one_candidate() {
+    let mut world = world();
+    expect_too_few_candidates(&mut world, &[b"Alice"]);
+}
+
+#[test]
+fn create_election_accepts_two_candidates() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.last_election_id().get(), 1);
+        });
+}
+
+#[test]
+fn time_status_counts_down_to_start_and_end() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+
+    let time_status = |world: &mut ScenarioWorld, timestamp: u64| {
+        set_timestamp(world, timestamp);
+        let mut status = (0, 0, 0);
+        world
+            .query()
+            .to(VOTING_APP)
+            .whitebox(voting_app::contract_obj, |sc| {
+                status = sc.get_time_status(1).into_tuple();
+            });
+        status
+    };
+
+    assert_eq!(
+        time_status(&mut world, START - 40),
+        (START - 40, 40, END - START + 40)
+    );
+    assert_eq!(
+        time_status(&mut world, START + 30),
+        (START + 30, 0, END - START - 30)
+    );
+    assert_eq!(time_status(&mut world, END + 5), (END + 5, 0, 0));
+}
+
+const GOV_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("GOV-123456");
+
+fn required_token_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_required_token(1, GOV_TOKEN.to_token_identifier());
+        });
+    world.set_esdt_balance(VOTER_1, GOV_TOKEN.as_bytes(), 10u64);
+    set_timestamp(&mut world, START);
+    world
+}
+
+#[test]
+fn token_holder_votes_without_being_listed() {
+    let mut world = required_token_setup();
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_with_token(1, ManagedBuffer::from("Alice"));
+        });
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+        });
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Already voted"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_with_token(1, ManagedBuffer::from("Bob"));
+        });
+}
+
+#[test]
+fn non_holder_cannot_vote_with_token() {
+    let mut world = required_token_setup();
+    world
+        .tx()
+        .from(VOTER_2)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Required token not held"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_with_token(1, ManagedBuffer::from("Alice"));
+        });
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Token holders vote with voteWithToken"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+#[test]
+fn token_holders_show_as_eligible() {
+    let mut world = required_token_setup();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.is_eligible(1, VOTER_1.to_managed_address()));
+            assert!(!sc.is_eligible(1, VOTER_2.to_managed_address()));
+            assert_eq!(
+                sc.get_voter_eligibility_source(1, VOTER_1.to_managed_address()),
+                ELIGIBILITY_TOKEN
+            );
+            assert_eq!(
+                sc.get_voter_eligibility_source(1, VOTER_2.to_managed_address()),
+                ELIGIBILITY_NONE
+            );
+
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(VOTER_1.to_managed_address());
+            addresses.push(VOTER_2.to_managed_address());
+            let flags: Vec<bool> = sc
+                .check_eligibility_batch(1, addresses)
+                .into_iter()
+                .map(|pair| pair.into_tuple().1)
+                .collect();
+            assert_eq!(flags, vec![true, false]);
+
+            let active: Vec<u64> = sc
+                .get_active_elections_for_voter(VOTER_1.to_managed_address())
+                .into_iter()
+                .collect();
+            assert_eq!(active, vec![1]);
+        });
+}
+
+#[test]
+fn can_vote_points_token_holders_to_vote_with_token() {
+    let mut world = required_token_setup();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let (ok, reason) = sc
+                .can_vote(
+                    1,
+                    VOTER_1.to_managed_address(),
+                    ManagedBuffer::from("Alice"),
+                )
+                .into_tuple();
+            assert!(!ok);
+            assert_eq!(
+                reason,
+                ManagedBuffer::from("Token holders vote with voteWithToken")
+            );
+        });
+}
+
+#[test]
+fn token_gated_election_has_no_eligibility_count() {
+    let mut world = required_token_setup();
+    add_voters(&mut world, 1, &[VOTER_2]);
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(sc.get_eligibility_count(1).into_option().is_none());
+        });
+}
+
+#[test]
+fn restricted_election_rejects_organizer_vote() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[ORGANIZER, VOTER_1]);
+    add_voters(&mut world, 2, &[ORGANIZER, VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_restrict_organizer(1, true);
+        });
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer cannot vote in this election"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+    vote(&mut world, VOTER_1, 1, "Alice");
+    vote(&mut world, ORGANIZER, 2, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+            assert_eq!(sc.vote_counts(2, &ManagedBuffer::from("Alice")).get(), 1);
+        });
+}
+
+#[test]
+fn eligibility_batch_keeps_input_order() {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[VOTER_1, VOTER_3]);
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let mut addresses = MultiValueEncoded::new();
+            for address in [OUTSIDER, VOTER_3, VOTER_2, VOTER_1] {
+                addresses.push(address.to_managed_address());
+            }
+            let rows: Vec<(Address, bool)> = sc
+                .check_eligibility_batch(1, addresses)
+                .into_iter()
+                .map(|row| {
+                    let (address, eligible) = row.into_tuple();
+                    (address.to_address(), eligible)
+                })
+                .collect();
+            assert_eq!(
+                rows,
+                vec![
+                    (OUTSIDER.to_address(), false),
+                    (VOTER_3.to_address(), true),
+                    (VOTER_2.to_address(), false),
+                    (VOTER_1.to_address(), true),
+                ]
+            );
+        });
+}
+
+fn restricted_organizer_setup() -> ScenarioWorld {
+    let mut world = world();
+    create_election(&mut world, &[b"Alice", b"Bob"]);
+    add_voters(&mut world, 1, &[ORGANIZER, VOTER_1]);
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_restrict_organizer(1, true);
+            sc.set_max_choices(1, 2);
+        });
+    world
+}
+
+#[test]
+fn restricted_organizer_cannot_abstain_or_vote_multi() {
+    let mut world = restricted_organizer_setup();
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer cannot vote in this election"))
+        .whitebox(voting_app::contract_obj, |sc| sc.abstain(1));
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer cannot vote in this election"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_multi(1, buffers(&[b"Alice", b"Bob"]));
+        });
+}
+
+#[test]
+fn restricted_organizer_cannot_vote_through_a_delegate() {
+    let mut world = restricted_organizer_setup();
+    delegate(&mut world, ORGANIZER, VOTER_1);
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer cannot vote in this election"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_as(
+                1,
+                ORGANIZER.to_managed_address(),
+                ManagedBuffer::from("Alice"),
+            );
+        });
+}
+
+#[test]
+fn restricted_organizer_cannot_vote_as_a_delegate() {
+    let mut world = restricted_organizer_setup();
+    delegate(&mut world, VOTER_1, ORGANIZER);
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Organizer cannot vote in this election"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_as(
+                1,
+                VOTER_1.to_managed_address(),
+                ManagedBuffer::from("Alice"),
+            );
+        });
+}
+
+#[test]
+fn try_vote_returns_false_for_restricted_organizer() {
+    let mut world = restricted_organizer_setup();
+    set_timestamp(&mut world, START);
+
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert!(!sc.try_vote(1, ManagedBuffer::from("Alice")));
+        });
+}
+
+#[test]
+fn registered_merkle_voter_can_use_plain_vote() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    create_merkle_election(&mut world, hash_sorted_pair(&leaf_1, &leaf_2));
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.register_with_merkle_proof(1, buffers(&[&leaf_2]));
+        });
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Alice");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Alice")).get(), 1);
+        });
+}
+
+#[test]
+fn listed_voter_can_use_plain_vote_in_merkle_election() {
+    let mut world = world();
+    create_merkle_election(&mut world, keccak256(b"unused"));
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "Bob");
+
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.vote_counts(1, &ManagedBuffer::from("Bob")).get(), 1);
+        });
+}
+
+#[test]
+fn plain_voter_cannot_also_vote_with_merkle_proof() {
+    let mut world = world();
+    let leaf_1 = keccak256(&VOTER_1.eval_to_array());
+    let leaf_2 = keccak256(&VOTER_2.eval_to_array());
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            let election_id = sc.create_election_with_merkle(
+                ManagedBuffer::from("Hybrid"),
+                START,
+                END,
+                ManagedBuffer::new_from_bytes(&hash_sorted_pair(&leaf_1, &leaf_2)),
+                buffers(&[b"Alice", b"Bob"]),
+            );
+            sc.set_encryption_public_key(election_id, ManagedBuffer::from("public-key"));
+        });
+    add_voters(&mut world, 1, &[VOTER_1]);
+    set_timestamp(&mut world, START);
+    vote(&mut world, VOTER_1, 1, "ciphertext-1");
+
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Already voted"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote_with_merkle(
+                1,
+                ManagedBuffer::from("nullifier-1"),
+                ManagedBuffer::from("ciphertext-2"),
+                buffers(&[&leaf_2]),
+            );
+        });
+}
+
+#[test]
+fn reveal_window_is_stored_as_voting_mode() {
+    let mut world = commit_reveal_setup();
+    world
+        .query()
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            assert_eq!(sc.get_voting_mode(1), VotingMode::CommitReveal);
+        });
+    world
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, "Election uses commit-reveal"))
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.vote(1, ManagedBuffer::from("Alice"));
+        });
+}
+
+/// Election 1 in `mode`, with every optional ballot path configured. VOTER_1 is not listed, so a
+/// Merkle election's hybrid eligibility does not let them through.
+fn mode_setup(mode: VotingMode) -> ScenarioWorld {
+    let mut world = world();
+    if mode == VotingMode::MerkleProof {
+        create_merkle_election(&mut world, keccak256(b"unused"));
+    } else {
+        create_election(&mut world, &[b"Alice", b"Bob"]);
+    }
+    world
+        .tx()
+        .from(ORGANIZER)
+        .to(VOTING_APP)
+        .whitebox(voting_app::contract_obj, |sc| {
+            sc.set_anonymous_root(1, ManagedBuffer::new_from_bytes(&keccak256(b"nullifier")));
+            sc.set_required_token(1, GOV_TOKEN.to_token_identifier());
+            match mode {
+                VotingMode::CommitReveal => sc.set_reveal_window(1, 50),
+                VotingMode::RankedChoice => sc.set_ranked_choice(1, true),
+                VotingMode::DirectVoting | VotingMode::MerkleProof => {}
+            }
+        });
+    set_timestamp(&mut world, START);
+    world
+}
+
+fn expect_mode_error(
+    mode: VotingMode,
+    error: &str,
+    call: impl FnOnce(voting_app::ContractObj<DebugApi>),
+) {
+    mode_setup(mode)
+        .tx()
+        .from(VOTER_1)
+        .to(VOTING_APP)
+        .returns(ExpectError(4, error))
+        .whitebox(voting_app::contract_obj, call);
+}
+
+fn vote_as_voter_2(sc: voting_app::ContractObj<DebugApi>) {
+    sc.vote_as(
+        1,
+        VOTER_2.to_managed_address(),
+        ManagedBuffer::from("Alice"),
+    );
+}
+
+fn vote_with_token(sc: voting_app::ContractObj<DebugApi>) {
+    sc.vote_with_token(1, ManagedBuffer::from("Alice"));
+}
+
+fn abstain(sc: voting_app::ContractObj<DebugApi>) {
+    sc.abstain(1);
+}
+
+fn vote_write_in(sc: voting_app::ContractObj<DebugApi>) {
+    sc.vote_write_in(1, ManagedBuffer::from("Zed"));
+}
+
+fn vote_for_proven_carol(sc: voting_app::ContractObj<DebugApi>) {
+    sc.vote_for_proven_candidate(
+        1,
+        ManagedBuffer::from("Carol"),
+        buffers(&[&keccak256(b"Bob")]),
+    );
+}
+
+fn vote_anonymously(sc: voting_app::ContractObj<DebugApi>) {
+    sc.vote_anonymous(
+        1,
+        ManagedBuffer::from("Alice"),
+        ManagedBuffer::from("nullifier"),
+        MultiValueEncoded::new(),
+    );
+}
+
+fn vote_ranked_alice(sc: voting_app::ContractObj<DebugApi>) {
+    sc.vote_ranked(1, buffers(&[b"Alice"]));
+}
+
+fn commit_alice(sc: voting_app::ContractObj<DebugApi>) {
+    sc.commit_vote(1, ManagedBuffer::new_from_bytes(&keccak256(b"Alicesalt")));
+}
+
+#[test]
+fn vote_as_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election uses Merkle proofs",
+        vote_as_voter_2,
+    );
+}
+
+#[test]
+fn vote_as_is_rejected_in_commit_reveal_elections() {
+    expect_mode_error(
+        VotingMode::CommitReveal,
+        "Election uses commit-reveal",
+        vote_as_voter_2,
+    );
+}
+
+#[test]
+fn vote_as_is_rejected_in_ranked_elections() {
+    expect_mode_error(
+        VotingMode::RankedChoice,
+        "Election uses ranked ballots",
+        vote_as_voter_2,
+    );
+}
+
+#[test]
+fn vote_with_token_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election uses Merkle proofs",
+        vote_with_token,
+    );
+}
+
+#[test]
+fn vote_with_token_is_rejected_in_commit_reveal_elections() {
+    expect_mode_error(
+        VotingMode::CommitReveal,
+        "Election uses commit-reveal",
+        vote_with_token,
+    );
+}
+
+#[test]
+fn vote_with_token_is_rejected_in_ranked_elections() {
+    expect_mode_error(
+        VotingMode::RankedChoice,
+        "Election uses ranked ballots",
+        vote_with_token,
+    );
+}
+
+#[test]
+fn abstain_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election uses Merkle proofs",
+        abstain,
+    );
+}
+
+#[test]
+fn abstain_is_rejected_in_commit_reveal_elections() {
+    expect_mode_error(
+        VotingMode::CommitReveal,
+        "Election uses commit-reveal",
+        abstain,
+    );
+}
+
+#[test]
+fn abstain_is_rejected_in_ranked_elections() {
+    expect_mode_error(
+        VotingMode::RankedChoice,
+        "Election uses ranked ballots",
+        abstain,
+    );
+}
+
+#[test]
+fn vote_write_in_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election uses Merkle proofs",
+        vote_write_in,
+    );
+}
+
+#[test]
+fn vote_write_in_is_rejected_in_commit_reveal_elections() {
+    expect_mode_error(
+        VotingMode::CommitReveal,
+        "Election uses commit-reveal",
+        vote_write_in,
+    );
+}
+
+#[test]
+fn vote_write_in_is_rejected_in_ranked_elections() {
+    expect_mode_error(
+        VotingMode::RankedChoice,
+        "Election uses ranked ballots",
+        vote_write_in,
+    );
+}
+
+#[test]
+fn vote_for_proven_candidate_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election uses Merkle proofs",
+        vote_for_proven_carol,
+    );
+}
+
+#[test]
+fn vote_for_proven_candidate_is_rejected_in_ranked_elections() {
+    expect_mode_error(
+        VotingMode::RankedChoice,
+        "Election uses ranked ballots",
+        vote_for_proven_carol,
+    );
+}
+
+#[test]
+fn vote_anonymous_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election uses Merkle proofs",
+        vote_anonymously,
+    );
+}
+
+#[test]
+fn vote_anonymous_is_rejected_in_commit_reveal_elections() {
+    expect_mode_error(
+        VotingMode::CommitReveal,
+        "Election uses commit-reveal",
+        vote_anonymously,
+    );
+}
+
+#[test]
+fn vote_anonymous_is_rejected_in_ranked_elections() {
+    expect_mode_error(
+        VotingMode::RankedChoice,
+        "Election uses ranked ballots",
+        vote_anonymously,
+    );
+}
+
+#[test]
+fn vote_multi_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election uses Merkle proofs",
+        |sc| {
+            sc.vote_multi(1, buffers(&[b"Alice"]));
+        },
+    );
+}
+
+#[test]
+fn vote_ranked_is_rejected_in_direct_elections() {
+    expect_mode_error(
+        VotingMode::DirectVoting,
+        "Election does not use ranked ballots",
+        vote_ranked_alice,
+    );
+}
+
+#[test]
+fn vote_ranked_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election does not use ranked ballots",
+        vote_ranked_alice,
+    );
+}
+
+#[test]
+fn vote_ranked_is_rejected_in_commit_reveal_elections() {
+    expect_mode_error(
+        VotingMode::CommitReveal,
+        "Election does not use ranked ballots",
+        vote_ranked_alice,
+    );
+}
+
+#[test]
+fn commit_vote_is_rejected_in_direct_elections() {
+    expect_mode_error(
+        VotingMode::DirectVoting,
+        "Election does not use commit-reveal",
+        commit_alice,
+    );
+}
+
+#[test]
+fn commit_vote_is_rejected_in_merkle_elections() {
+    expect_mode_error(
+        VotingMode::MerkleProof,
+        "Election does not use commit-reveal",
+        commit_alice,
+    );
+}
+
+#[test]
+fn commit_vote_is_rejected_in_ranked_elections() {
+    expect_mode_error(
+        VotingMode::RankedChoice,
+        "Election does not use commit-reveal",
+        commit_alice,
+    );
+}
+
+#[test]
+fn vote_with_merkle_is_rejected_in_direct_elections() {
+    expect_mode_error(
+        VotingMode::DirectVoting,
+        "Election not configured for Merkle voting",
+        |sc| {
+            sc.vote_with_merkle(
+                1,
+                ManagedBuffer::from("nullifier"),
+                ManagedBuffer::from("Alice"),
+                MultiValueEncoded::new(),
+            );
+        },
+    );
+}
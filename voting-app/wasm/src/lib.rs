@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           16
+// Endpoints:                          163
 // Async Callback (empty):               1
-// Total number of exported functions:  19
+// Total number of exported functions: 166
 
 #![no_std]
 
@@ -22,18 +22,165 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         createElection => create_election
         createElectionWithMerkle => create_election_with_merkle
+        addCreator => add_creator
+        removeCreator => remove_creator
+        addAdmin => add_admin
+        removeAdmin => remove_admin
+        isAdmin => is_admin
+        cloneElection => clone_election
+        setMaxElectionDuration => set_max_election_duration
+        maxElectionDuration => get_max_election_duration
+        setElectionDefaults => set_election_defaults
         setEncryptionPublicKey => set_encryption_public_key
+        setOpenTally => set_open_tally
         addVoters => add_voters
+        addWeightedVoters => add_weighted_voters
+        setRestrictOrganizer => set_restrict_organizer
+        setOpenRegistration => set_open_registration
+        registerSelf => register_self
+        setEligibleCountOverride => set_eligible_count_override
+        removeVoter => remove_voter
+        registerWithMerkleProof => register_with_merkle_proof
+        setCandidateRoot => set_candidate_root
+        setAnonymousRoot => set_anonymous_root
+        registerCandidateWithProof => register_candidate_with_proof
+        addCandidates => add_candidates
+        setCandidateMetadata => set_candidate_metadata
+        getCandidateMetadata => get_candidate_metadata
+        setBallotOrderMode => set_ballot_order_mode
+        setMaxChoices => set_max_choices
+        setLastVoteWins => set_last_vote_wins
+        setMaxDelegatedWeight => set_max_delegated_weight
+        setRevealWindow => set_reveal_window
+        setDisplayThreshold => set_display_threshold
+        setRevealDeposit => set_reveal_deposit
+        setStakeContract => set_stake_contract
+        setNftGate => set_nft_gate
+        setRequiredToken => set_required_token
+        setTierWeight => set_tier_weight
+        registerVotingNft => register_voting_nft
+        setAllowedMethods => set_allowed_methods
+        setRankedChoice => set_ranked_choice
+        setTallyMethod => set_tally_method
+        setCandidateCap => set_candidate_cap
+        disqualifyCandidate => disqualify_candidate
+        setNotary => set_notary
+        notarizeResults => notarize_results
+        setWriteInsEnabled => set_write_ins_enabled
+        approveWriteIn => approve_write_in
+        rejectWriteIn => reject_write_in
+        setMinTotalWeight => set_min_total_weight
+        setQuorum => set_quorum
+        takeResultsSnapshot => take_results_snapshot
+        migrateAllElectionsTo => migrate_all_elections_to
+        proposeOrganizer => propose_organizer
+        acceptOrganizer => accept_organizer
+        renounceOrganizer => renounce_organizer
+        migrateElectionsChunk => migrate_elections_chunk
+        setAutoRevoteOnTie => set_auto_revote_on_tie
+        setElectionGroup => set_election_group
         endElection => end_election
+        endElectionBatch => end_election_batch
+        pauseElection => pause_election
+        resumeElection => resume_election
+        pause => pause
+        unpause => unpause
+        pauseAllElections => pause_all_elections
+        purgeElection => purge_election
+        reopenElection => reopen_election
+        cancelElection => cancel_election
+        extendElection => extend_election
         forceEndElection => force_end_election
         vote => vote
+        voteAs => vote_as
+        voteWithToken => vote_with_token
+        changeVote => change_vote
+        abstain => abstain
+        tryVote => try_vote
         voteWithMerkle => vote_with_merkle
+        voteAnonymous => vote_anonymous
+        commitVote => commit_vote
+        revealVote => reveal_vote
+        sweepUnrevealed => sweep_unrevealed
+        setBadgeCollection => set_badge_collection
+        claimBadge => claim_badge
+        delegate => delegate
+        voteMulti => vote_multi
+        voteRanked => vote_ranked
+        voteWriteIn => vote_write_in
+        voteForProvenCandidate => vote_for_proven_candidate
         verifyMerkleProof => verify_merkle_proof
         getAllElections => get_all_elections
+        getElectionInfo => get_election_info
+        getVotingMode => get_voting_mode
+        getElectionCount => get_election_count
+        getElectionsPaged => get_elections_paged
+        getUpcomingElections => get_upcoming_elections
+        getStateCounts => get_state_counts
+        getElectionMethodStats => get_election_method_stats
+        getElectionDefaults => get_election_defaults
+        getContractConfig => get_contract_config
+        getActiveElectionsForVoter => get_active_elections_for_voter
+        getParticipationRate => get_participation_rate
+        getDelegatedPower => get_delegated_power
+        getElectionsRequiringReveal => get_elections_requiring_reveal
+        getHeadToHead => get_head_to_head
+        getCondorcetWinner => get_condorcet_winner
+        computeIrvWinner => compute_irv_winner
+        getBordaResults => get_borda_results
+        hasVoted => has_voted_view
+        isEligible => is_eligible
+        checkEligibilityBatch => check_eligibility_batch
+        getVoterChoice => get_voter_choice
+        canVote => can_vote
+        getFinalizationPreconditions => get_finalization_preconditions
+        previewFinalize => preview_finalize
+        getEndedUnfinalizedElections => get_ended_unfinalized_elections
+        getElectionCountByOrganizer => get_election_count_by_organizer
+        getCreators => get_creators
+        isPaused => is_paused
+        isCancelled => is_cancelled
+        getOrganizer => get_organizer
+        getPendingOrganizer => get_pending_organizer
+        isOrganizerRenounced => is_organizer_renounced
         isOrganizer => is_organizer
+        getElectionCreator => get_election_creator
+        getElectionOrganizer => get_election_organizer
+        getElectionRaw => get_election_raw
+        getRevoteElection => get_revote_election
+        getBlockTimeContext => get_block_time_context
+        getTimeStatus => get_time_status
+        maxCandidates => max_candidates
         getElectionResults => get_election_results
+        getResultsBatch => get_results_batch
+        getCandidateVotes => get_candidate_votes
+        getElectionResultsFull => get_election_results_full
+        getWinner => get_winner
+        getWinners => get_winners
+        getGroupWinners => get_group_winners
+        getElectionResultsCsv => get_election_results_csv
+        getResultsHash => get_results_hash
+        getAuditBundle => get_audit_bundle
+        getVotesRoot => get_votes_root
+        verifyVoteInclusion => verify_vote_inclusion
+        getVoterCountAtFinalization => get_voter_count_at_finalization
+        getTurnout => get_turnout
+        getPendingVoters => get_pending_voters
+        getPendingVotersPaged => get_pending_voters_paged
+        getAbstentions => get_abstentions
+        getEligibilityCount => get_eligibility_count
+        isQuorumMet => is_quorum_met
+        isResultsNotarized => is_results_notarized
+        getPendingWriteIns => get_pending_write_ins
+        getElectionResultsWithStatus => get_election_results_with_status
+        getResultsDelta => get_results_delta
+        getCandidateTrend => get_candidate_trend
+        getTurnoutOverTime => get_turnout_over_time
         getElectionCandidates => get_election_candidates
+        getVoterEligibilitySource => get_voter_eligibility_source
+        getEligibleButNotRegistered => get_eligible_but_not_registered
         getEncryptionPublicKey => get_encryption_public_key
+        isOpenTally => is_open_tally
         getEncryptedVotes => get_encrypted_votes
         publishResults => publish_results
     )
@@ -59,8 +59,8 @@ export function CreateElectionForm({ onClose, onSuccess }: CreateElectionFormPro
         .map(c => c.trim())
         .filter(c => c.length > 0);
 
-      if (candidatesList.length === 0) {
-        alert('Please add at least one candidate');
+      if (candidatesList.length < 2) {
+        alert('Please add at least two candidates');
         setIsSubmitting(false);
         return;
       }
@@ -23,10 +23,12 @@ export function hashAddress(address: string): Buffer {
 }
 
 /**
- * Hash two buffers: keccak256(left + right)
+ * Hash two buffers, smaller first: keccak256(min + max)
+ * Matches the contract's sorted-pair hashing (merkletreejs `sortPairs: true`)
  */
 export function hashNode(left: Buffer, right: Buffer): Buffer {
-  return Buffer.from(keccak256(Buffer.concat([left, right])));
+  const pair = Buffer.compare(left, right) <= 0 ? [left, right] : [right, left];
+  return Buffer.from(keccak256(Buffer.concat(pair)));
 }
 
 /**
@@ -137,8 +139,7 @@ export function verifyMerkleProof(
   let hash = hashAddress(address);
 
   for (const sibling of proof) {
-    // Contract alternates between left/right, we need to match its order
-    // Generally: hash = keccak256(hash + sibling) or keccak256(sibling + hash)
+    // Pairs are sorted before hashing, so the sibling's side does not matter
     hash = hashNode(hash, sibling);
   }
 